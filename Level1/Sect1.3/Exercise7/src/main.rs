@@ -11,6 +11,26 @@
 
 use std::io;
 
+// Multiply value by 2^n with a left shift, or None if the result overflows
+// Shifting moves bits out at the top; the shift was lossless (and kept the
+// sign) exactly when shifting back arithmetically restores the value.
+// n >= 64 only fits for 0, since every bit of any other value is shifted out.
+pub fn mul_pow2_checked(value: i64, n: u32) -> Option<i64> {
+    if value == 0 {
+        return Some(0);
+    }
+    if n >= i64::BITS {
+        return None;
+    }
+    
+    let result = value << n;
+    if result >> n == value {
+        Some(result)
+    } else {
+        None
+    }
+}
+
 fn main() {
     println!("Efficient Multiplication by 2^n using Bit Shifting");
     println!("===================================================\n");
@@ -91,12 +111,9 @@ fn main() {
     println!("┌─────────┬──────────────┬─────────────────────────┐");
     println!("│ n       │ 2^n          │ Operation               │");
     println!("├─────────┼──────────────┼─────────────────────────┤");
-    println!("│ 0       │ 1            │ {} << 0 = {:6}        │", number, number << 0);
-    println!("│ 1       │ 2            │ {} << 1 = {:6}        │", number, number << 1);
-    println!("│ 2       │ 4            │ {} << 2 = {:6}        │", number, number << 2);
-    println!("│ 3       │ 8            │ {} << 3 = {:6}        │", number, number << 3);
-    println!("│ 4       │ 16           │ {} << 4 = {:6}        │", number, number << 4);
-    println!("│ 5       │ 32           │ {} << 5 = {:6}        │", number, number << 5);
+    for shift in 0..=5 {
+        println!("│ {:<7} │ {:<12} │ {} << {} = {:6}        │", shift, 1 << shift, number, shift, number << shift);
+    }
     println!("└─────────┴──────────────┴─────────────────────────┘");
     
     // Performance note
@@ -108,11 +125,41 @@ fn main() {
     println!("• Compiler often optimizes × 2^n to << n automatically");
     println!("• Useful for low-level programming and embedded systems");
     
-    // Warning about overflow
-    if n >= 16 {
-        println!("\n⚠️  WARNING: Large shifts may cause overflow!");
-        println!("   For i32, shifting left by {} or more bits", 32);
-        println!("   can lead to unexpected results or overflow.");
+    // Overflow checking
+    println!("\n╔════════════════════════════════════════════════════╗");
+    println!("║              OVERFLOW CHECKING                    ║");
+    println!("╚════════════════════════════════════════════════════╝");
+    println!("Plain << silently drops the bits shifted out at the top.");
+    println!("mul_pow2_checked() detects this and returns None instead:");
+    for shift in [n, 40, 62, 63] {
+        match mul_pow2_checked(number as i64, shift) {
+            Some(value) => println!("  {} × 2^{} = {}", number, shift, value),
+            None => println!("  {} × 2^{} overflows i64", number, shift),
+        }
     }
     
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_shifts() {
+        assert_eq!(mul_pow2_checked(5, 0), Some(5));
+        assert_eq!(mul_pow2_checked(5, 3), Some(40));
+        assert_eq!(mul_pow2_checked(-7, 4), Some(-112));
+        assert_eq!(mul_pow2_checked(1, 62), Some(1 << 62));
+        assert_eq!(mul_pow2_checked(-1, 63), Some(i64::MIN));
+    }
+
+    #[test]
+    fn test_overflowing_shifts() {
+        assert_eq!(mul_pow2_checked(1, 63), None);  // Would flip the sign
+        assert_eq!(mul_pow2_checked(3, 62), None);
+        assert_eq!(mul_pow2_checked(i64::MAX, 1), None);
+        assert_eq!(mul_pow2_checked(i64::MIN, 1), None);
+        assert_eq!(mul_pow2_checked(1, 64), None);
+        assert_eq!(mul_pow2_checked(0, 100), Some(0));
+    }
+}
@@ -0,0 +1,81 @@
+// Byte Reader Module
+// ------------------
+// Reads input one byte at a time, like C's getchar(), with end-of-file
+// reported explicitly instead of through a special EOF value.
+// This file is the only copy: the character counting, string length and
+// echo programs include it with #[path] so they all handle EOF and read
+// errors the same way.
+//
+// C:    int c = getchar();  if (c == EOF) ...
+// Rust: match reader.next_byte()? { Some(c) => ..., None => ... }
+
+use std::io::{self, Read};
+
+// Wraps any byte source (stdin, a file, an in-memory Cursor, ...)
+pub struct ByteReader<R: Read> {
+    reader: R,
+    buffer: [u8; 1],  // Single-byte buffer, one getchar() at a time
+}
+
+impl<R: Read> ByteReader<R> {
+    // Create a reader over the given byte source
+    pub fn new(reader: R) -> Self {
+        ByteReader {
+            reader,
+            buffer: [0u8; 1],
+        }
+    }
+
+    // Read the next byte
+    // Returns Ok(None) at end of input (Ctrl+D on Unix, Ctrl+Z on Windows)
+    pub fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(self.buffer[0])),
+                // A signal interrupted the read, nothing was consumed: retry
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_reads_bytes_until_eof() {
+        let mut reader = ByteReader::new(Cursor::new(b"Hi\n".to_vec()));
+
+        assert_eq!(reader.next_byte().unwrap(), Some(b'H'));
+        assert_eq!(reader.next_byte().unwrap(), Some(b'i'));
+        assert_eq!(reader.next_byte().unwrap(), Some(b'\n'));
+        assert_eq!(reader.next_byte().unwrap(), None);
+
+        // Stays at EOF once reached
+        assert_eq!(reader.next_byte().unwrap(), None);
+    }
+
+    #[test]
+    fn test_empty_input_is_immediate_eof() {
+        let mut reader = ByteReader::new(Cursor::new(Vec::new()));
+        assert_eq!(reader.next_byte().unwrap(), None);
+    }
+
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("device unplugged"))
+        }
+    }
+
+    #[test]
+    fn test_read_errors_are_reported() {
+        let mut reader = ByteReader::new(FailingReader);
+        assert!(reader.next_byte().is_err());
+    }
+}
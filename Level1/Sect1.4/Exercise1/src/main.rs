@@ -11,7 +11,12 @@
 //
 // Note: In Rust, we read from stdin byte by byte to mimic getchar()
 
-use std::io::{self, Read};
+mod byte_reader;
+mod text_stats;
+
+use byte_reader::ByteReader;
+use std::io;
+use text_stats::text_stats;
 
 fn main() {
     println!("Text Statistics Counter");
@@ -19,61 +24,17 @@ fn main() {
     println!("Type your text (press Ctrl+D on Unix/Mac or Ctrl+Z+Enter on Windows to finish):");
     println!();
     
-    // Initialize counters
-    let mut char_count = 0;
-    let mut word_count = 0;
-    let mut line_count = 0;
-    let mut in_word = false;  // Track if we're currently inside a word
-    
     // Get stdin handle for byte-by-byte reading
     let stdin = io::stdin();
-    let mut bytes = stdin.lock().bytes();
+    let mut reader = ByteReader::new(stdin.lock());
+    let mut bytes = Vec::new();
     
     // While loop to read characters until EOF (similar to C's getchar())
     loop {
-        // Read next byte (similar to getchar())
-        let byte_result = bytes.next();
-        
-        // Check for EOF (None means end of input)
-        match byte_result {
-            None => break,  // EOF reached (Ctrl+D on Unix, Ctrl+Z on Windows)
-            Some(Ok(byte)) => {
-                let ch = byte as char;
-                
-                // Count every character
-                char_count += 1;
-                
-                // Count lines
-                if ch == '\n' {
-                    line_count += 1;
-                }
-                
-                // Word counting logic
-                // Check if current character is whitespace
-                let is_whitespace = ch == ' ' || ch == '\t' || ch == '\n' || ch == '\r';
-                
-                if is_whitespace {
-                    // We're at whitespace
-                    if in_word {
-                        // We were in a word, now we're leaving it
-                        in_word = false;
-                    }
-                    // Multiple consecutive spaces: do nothing (already outside word)
-                } else {
-                    // We're at a non-whitespace character
-                    if !in_word {
-                        // We weren't in a word, now entering one
-                        word_count += 1;
-                        in_word = true;
-                    }
-                    // If already in a word, just continue
-                }
-                
-                // Optional: Show what's being typed (comment out for cleaner output)
-                // print!("{}", ch);
-                // io::Write::flush(&mut io::stdout()).unwrap();
-            }
-            Some(Err(_)) => {
+        match reader.next_byte() {
+            Ok(None) => break,  // EOF reached (Ctrl+D on Unix, Ctrl+Z on Windows)
+            Ok(Some(byte)) => bytes.push(byte),
+            Err(_) => {
                 // Error reading input
                 eprintln!("Error reading input");
                 break;
@@ -81,18 +42,16 @@ fn main() {
         }
     }
     
-    // Adjust line count if file doesn't end with newline but has content
-    if char_count > 0 && line_count == 0 {
-        line_count = 1;  // At least one line if there's any content
-    }
+    // Count everything in one pass over the collected text
+    let stats = text_stats(&String::from_utf8_lossy(&bytes));
     
     // Display results
     println!("\n╔════════════════════════════════════════════════════╗");
     println!("║                    STATISTICS                      ║");
     println!("╚════════════════════════════════════════════════════╝");
-    println!("  Characters: {:6}", char_count);
-    println!("  Words:      {:6}", word_count);
-    println!("  Lines:      {:6}", line_count);
+    println!("  Characters: {:6}", stats.chars);
+    println!("  Words:      {:6}", stats.words);
+    println!("  Lines:      {:6}", stats.lines);
     
     // Test examples
     println!("\n╔════════════════════════════════════════════════════╗");
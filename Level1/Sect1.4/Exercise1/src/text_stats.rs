@@ -0,0 +1,73 @@
+// Text Statistics Module
+// ----------------------
+// The character, word and line counting from the text statistics
+// exercise, separated from the input loop so it can be tested on plain
+// strings instead of keyboard input.
+//
+// Words are counted with a small state machine: a word starts at each
+// transition from whitespace to non-whitespace, so runs of spaces never
+// count as extra words (the same rule as the Unix 'wc' command).
+
+// Counts produced by text_stats()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextStats {
+    pub chars: usize,  // Every character, including spaces and newlines
+    pub words: usize,  // Runs of non-whitespace characters
+    pub lines: usize,  // Newlines, plus one for an unterminated last line
+}
+
+// Count the characters, words and lines in the input
+pub fn text_stats(input: &str) -> TextStats {
+    let mut stats = TextStats::default();
+    let mut in_word = false;  // Track if we're currently inside a word
+
+    for ch in input.chars() {
+        stats.chars += 1;
+
+        if ch == '\n' {
+            stats.lines += 1;
+        }
+
+        let is_whitespace = ch == ' ' || ch == '\t' || ch == '\n' || ch == '\r';
+        if is_whitespace {
+            // Leaving a word (or still between words): nothing to count
+            in_word = false;
+        } else if !in_word {
+            // Entering a word
+            stats.words += 1;
+            in_word = true;
+        }
+    }
+
+    // Text after the last newline is a line too
+    if !input.is_empty() && !input.ends_with('\n') {
+        stats.lines += 1;
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hello_world() {
+        assert_eq!(text_stats("Hello World"), TextStats { chars: 11, words: 2, lines: 1 });
+        assert_eq!(text_stats("Hello\nWorld"), TextStats { chars: 11, words: 2, lines: 2 });
+        assert_eq!(text_stats("Hello World\n"), TextStats { chars: 12, words: 2, lines: 1 });
+    }
+
+    #[test]
+    fn test_multiple_spaces() {
+        assert_eq!(text_stats("Hello    World"), TextStats { chars: 14, words: 2, lines: 1 });
+        assert_eq!(text_stats("a \t b\r\n\n c"), TextStats { chars: 10, words: 3, lines: 3 });
+    }
+
+    #[test]
+    fn test_leading_and_trailing_whitespace() {
+        assert_eq!(text_stats("  Hello  "), TextStats { chars: 9, words: 1, lines: 1 });
+        assert_eq!(text_stats("   "), TextStats { chars: 3, words: 0, lines: 1 });
+        assert_eq!(text_stats(""), TextStats::default());
+    }
+}
@@ -15,6 +15,10 @@
 //
 // Note: In Rust, we use format specifiers like {:10.1} instead of %10.1f
 
+mod temperature;
+
+use temperature::{celsius_to_kelvin, fahrenheit_to_celsius};
+
 fn main() {
     // Maintenance-friendly constants (easy to adjust)
     const START_FAHRENHEIT: f64 = 0.0;
@@ -36,9 +40,8 @@ fn main() {
     
     // While loop construction as required
     while fahrenheit <= END_FAHRENHEIT {
-        // Calculate Celsius using the formula
-        // Note: We use 5.0/9.0 to ensure floating point division
-        let celsius = (5.0 / 9.0) * (fahrenheit - 32.0);
+        // Calculate Celsius using the formula (see the temperature module)
+        let celsius = fahrenheit_to_celsius(fahrenheit);
         
         // Print the row with proper formatting
         // {:10.1} means: 10 characters wide, 1 decimal place
@@ -69,6 +72,7 @@ fn main() {
     println!("  •  32°F =   0.0°C (Water freezes)");
     println!("  •  98.6°F = 37.0°C (Body temperature)");
     println!("  • 212°F = 100.0°C (Water boils)");
+    println!("  • 212°F = {:.2}K (in Kelvin)", celsius_to_kelvin(fahrenheit_to_celsius(212.0)));
     
     // Note about maintenance
     println!("\n╔════════════════════════════════════════╗");
@@ -0,0 +1,100 @@
+// Temperature Module
+// ------------------
+// Celsius, Fahrenheit and Kelvin conversions shared by the temperature
+// table exercises (1.4.4 and 1.4.5), so the formulas live in one place
+// and can be tested without printing a table. 1.4.5 includes this file
+// with #[path] rather than keeping its own copy.
+//
+// Fahrenheit = (9/5) * Celsius + 32
+// Celsius    = (5/9) * (Fahrenheit - 32)
+// Kelvin     = Celsius + 273.15
+#![allow(dead_code)]  // Each exercise uses only some of the conversions
+
+// Offset between the Celsius and Kelvin scales
+pub const KELVIN_OFFSET: f64 = 273.15;
+
+// Convert degrees Celsius to degrees Fahrenheit
+pub fn celsius_to_fahrenheit(c: f64) -> f64 {
+    (9.0 / 5.0) * c + 32.0
+}
+
+// Convert degrees Fahrenheit to degrees Celsius
+// Note: 5.0/9.0, not 5/9, which is integer division and gives 0 (in C too)
+pub fn fahrenheit_to_celsius(f: f64) -> f64 {
+    (5.0 / 9.0) * (f - 32.0)
+}
+
+// Convert degrees Celsius to Kelvin
+pub fn celsius_to_kelvin(c: f64) -> f64 {
+    c + KELVIN_OFFSET
+}
+
+// Convert Kelvin to degrees Celsius
+pub fn kelvin_to_celsius(k: f64) -> f64 {
+    k - KELVIN_OFFSET
+}
+
+// Build a Celsius -> Fahrenheit table from start to end (inclusive)
+// Returns (celsius, fahrenheit) rows for start, start + step, ...
+// If step does not divide the range evenly the last row is the largest
+// value not past end, so end itself is left out (0..=10 step 3 stops at 9).
+// Each row is computed as start + i * step rather than by repeated
+// addition, so rounding errors do not build up along the table.
+// A step <= 0 or an end before start gives an empty table.
+pub fn conversion_table(start: f64, end: f64, step: f64) -> Vec<(f64, f64)> {
+    if step <= 0.0 || end < start {
+        return Vec::new();
+    }
+
+    // Small tolerance so e.g. 0.0..=1.0 step 0.1 still includes 1.0
+    let rows = ((end - start) / step + 1e-9).floor() as usize + 1;
+    (0..rows)
+        .map(|i| {
+            let celsius = start + i as f64 * step;
+            (celsius, celsius_to_fahrenheit(celsius))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_points() {
+        assert_eq!(celsius_to_fahrenheit(0.0), 32.0);
+        assert_eq!(celsius_to_fahrenheit(100.0), 212.0);
+        assert_eq!(fahrenheit_to_celsius(32.0), 0.0);
+        assert_eq!(fahrenheit_to_celsius(212.0), 100.0);
+        assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+    }
+
+    #[test]
+    fn test_kelvin() {
+        assert_eq!(celsius_to_kelvin(0.0), 273.15);
+        assert_eq!(kelvin_to_celsius(0.0), -273.15);
+        assert_eq!(kelvin_to_celsius(celsius_to_kelvin(25.0)), 25.0);
+    }
+
+    #[test]
+    fn test_conversion_table() {
+        let table = conversion_table(0.0, 20.0, 1.0);
+        assert_eq!(table.len(), 21);
+        assert_eq!(table[0], (0.0, 32.0));
+        assert_eq!(table[10], (10.0, 50.0));
+        assert_eq!(table[20], (20.0, 68.0));
+    }
+
+    #[test]
+    fn test_conversion_table_uneven_step() {
+        let table = conversion_table(0.0, 20.0, 3.0);
+        let celsius: Vec<f64> = table.iter().map(|&(c, _)| c).collect();
+        assert_eq!(celsius, [0.0, 3.0, 6.0, 9.0, 12.0, 15.0, 18.0]);
+
+        // Fractional steps still reach the end
+        assert_eq!(conversion_table(0.0, 1.0, 0.1).len(), 11);
+
+        assert!(conversion_table(0.0, 20.0, 0.0).is_empty());
+        assert!(conversion_table(20.0, 0.0, 1.0).is_empty());
+    }
+}
@@ -11,6 +11,11 @@
 // Note: Formula to convert Celsius to Fahrenheit:
 // Fahrenheit = (9/5) * Celsius + 32
 
+#[path = "../../Exercise4/src/temperature.rs"]
+mod temperature;
+
+use temperature::{celsius_to_fahrenheit, conversion_table};
+
 fn main() {
     // Configuration constants
     const START_CELSIUS: i32 = 0;
@@ -29,14 +34,13 @@ fn main() {
     
     // For loop construction as required
     // Rust's for loop with range is idiomatic
-    for celsius in (START_CELSIUS..=END_CELSIUS).step_by(STEP_SIZE as usize) {
-        // Calculate Fahrenheit using the formula
-        // Fahrenheit = (9/5) * Celsius + 32
-        let fahrenheit = (9.0 / 5.0) * (celsius as f64) + 32.0;
-        
+    // The rows come from the temperature module, which applies
+    // Fahrenheit = (9/5) * Celsius + 32
+    let table = conversion_table(START_CELSIUS as f64, END_CELSIUS as f64, STEP_SIZE as f64);
+    for (celsius, fahrenheit) in table {
         // Print the row with proper formatting
-        // {:7} for celsius (integer), {:7.1} for fahrenheit (1 decimal)
-        println!("│      {:2}      │     {:5.1}    │", celsius, fahrenheit);
+        // {:2.0} for celsius (whole degrees), {:5.1} for fahrenheit (1 decimal)
+        println!("│      {:2.0}      │     {:5.1}    │", celsius, fahrenheit);
     }
     
     println!("└──────────────┴──────────────┘");
@@ -82,7 +86,7 @@ fn _c_style_for_loop_example() {
     // Rust equivalent using while:
     let mut celsius = 0;
     while celsius <= 19 {
-        let fahrenheit = (9.0 / 5.0) * (celsius as f64) + 32.0;
+        let fahrenheit = celsius_to_fahrenheit(celsius as f64);
         println!("{:2}°C = {:5.1}°F", celsius, fahrenheit);
         celsius += 1;
     }
@@ -93,7 +97,7 @@ fn _iterator_style_example() {
     println!("Iterator style in Rust:");
     
     (0..=19).for_each(|celsius| {
-        let fahrenheit = (9.0 / 5.0) * (celsius as f64) + 32.0;
+        let fahrenheit = celsius_to_fahrenheit(celsius as f64);
         println!("{:2}°C = {:5.1}°F", celsius, fahrenheit);
     });
 }
@@ -4,8 +4,26 @@
 // 6! (six factorial) is the same as 6 * 5 * 4 * 3 * 2 * 1
 // Must make use of a recursive function.
 
+use std::fmt;
 use std::io;
 
+// Error returned by checked_factorial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorialError {
+    // n! does not fit in a u64 (happens from 21! onwards)
+    Overflow { n: u32 },
+}
+
+impl fmt::Display for FactorialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactorialError::Overflow { n } => write!(f, "{}! overflows a 64-bit integer", n),
+        }
+    }
+}
+
+impl std::error::Error for FactorialError {}
+
 // Recursive factorial function
 fn factorial(n: u32) -> u64 {
     // Base case: 0! = 1 and 1! = 1
@@ -17,6 +35,16 @@ fn factorial(n: u32) -> u64 {
     }
 }
 
+// Factorial that reports overflow instead of wrapping
+// Iterative rather than recursive: the fold stops at the first failed
+// checked_mul (by 21), so a huge n cannot overflow the stack
+// The error always names the requested n, wherever the overflow happened
+pub fn checked_factorial(n: u32) -> Result<u64, FactorialError> {
+    (2..=n as u64)
+        .try_fold(1u64, |acc, k| acc.checked_mul(k))
+        .ok_or(FactorialError::Overflow { n })
+}
+
 // Helper function to show the calculation steps
 fn factorial_with_steps(n: u32, depth: usize) -> u64 {
     // Print indentation to show recursion depth
@@ -54,13 +82,14 @@ fn main() {
     let number: u32 = input.trim().parse()
         .expect("Please enter a valid non-negative integer");
     
-    // Check for overflow risk
-    if number > 20 {
-        println!("Warning: Factorials larger than 20! may overflow!");
-    }
-    
-    // Calculate factorial
-    let result = factorial(number);
+    // Calculate factorial, stopping before anything overflows
+    let result = match checked_factorial(number) {
+        Ok(result) => result,
+        Err(err) => {
+            println!("Error: {} (the largest supported value is 20)", err);
+            return;
+        }
+    };
     
     // Print result
     println!("\n╔════════════════════════════════════════╗");
@@ -109,3 +138,27 @@ fn main() {
     println!("• Each call waits for the next to return");
     println!("• Results bubble up from base case");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_factorial() {
+        assert_eq!(checked_factorial(0), Ok(1));
+        assert_eq!(checked_factorial(6), Ok(720));
+        assert_eq!(checked_factorial(20), Ok(2_432_902_008_176_640_000));
+        assert_eq!(checked_factorial(20), Ok(factorial(20)));
+    }
+
+    #[test]
+    fn test_checked_factorial_overflow() {
+        assert_eq!(checked_factorial(21), Err(FactorialError::Overflow { n: 21 }));
+        assert_eq!(checked_factorial(25), Err(FactorialError::Overflow { n: 25 }));
+        assert_eq!(checked_factorial(u32::MAX), Err(FactorialError::Overflow { n: u32::MAX }));
+        assert_eq!(
+            FactorialError::Overflow { n: 21 }.to_string(),
+            "21! overflows a 64-bit integer"
+        );
+    }
+}
@@ -49,6 +49,32 @@ fn printnumber(n: i32) {
     }
 }
 
+// Render a number in any radix from 2 to 36, digit by digit
+// Same recursion as printnumber(), but collected into a String so the
+// result can be checked. Digits above 9 use lowercase letters.
+// Works on the unsigned magnitude so i64::MIN needs no special case.
+// Panics if radix is outside 2..=36.
+pub fn print_number_radix(n: i64, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix must be between 2 and 36, got {}", radix);
+    
+    let mut output = String::new();
+    if n < 0 {
+        output.push('-');
+    }
+    push_digits(n.unsigned_abs(), radix as u64, &mut output);
+    output
+}
+
+// Recursive helper: push all leading digits first, then the last one
+fn push_digits(n: u64, radix: u64, output: &mut String) {
+    if n >= radix {
+        push_digits(n / radix, radix, output);
+    }
+    let digit = std::char::from_digit((n % radix) as u32, radix as u32)
+        .expect("remainder is always a valid digit");
+    output.push(digit);
+}
+
 // Helper function to show the recursion process
 fn printnumber_with_trace(n: i32, depth: usize) {
     // Print indentation for visualization
@@ -130,6 +156,11 @@ fn main() {
         println!();
     }
     
+    println!("\nOther radixes:");
+    println!("  print_number_radix({}, 2)  = {}", number, print_number_radix(number as i64, 2));
+    println!("  print_number_radix({}, 16) = {}", number, print_number_radix(number as i64, 16));
+    println!("  print_number_radix({}, 36) = {}", number, print_number_radix(number as i64, 36));
+    
     // Show recursion trace for a smaller number
     if number.abs() < 1000 && number != 0 {
         println!("\n╔════════════════════════════════════════╗");
@@ -155,3 +186,30 @@ fn main() {
     println!("      → print '1' (base case)");
     println!("Result: '1' '2' '3'");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_number_radix() {
+        assert_eq!(print_number_radix(255, 16), "ff");
+        assert_eq!(print_number_radix(-10, 2), "-1010");
+        assert_eq!(print_number_radix(0, 10), "0");
+        assert_eq!(print_number_radix(35, 36), "z");
+        assert_eq!(print_number_radix(123, 10), "123");
+    }
+
+    #[test]
+    fn test_print_number_radix_extremes() {
+        assert_eq!(print_number_radix(i64::MIN, 10), "-9223372036854775808");
+        assert_eq!(print_number_radix(i64::MIN, 16), "-8000000000000000");
+        assert_eq!(print_number_radix(i64::MAX, 10), i64::MAX.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be between 2 and 36")]
+    fn test_print_number_radix_invalid() {
+        print_number_radix(10, 37);
+    }
+}
@@ -1,7 +1,7 @@
 // Exercise 2 - Section 1.6 (Extended Macro Definitions)
 // ------------------------------------------------------
 // This file contains all macro definitions including PRINT1, PRINT2,
-// MAX2, and MAX3 (equivalent to the complete Defs.h)
+// MAX2, MAX3, MIN2 and MIN3 (equivalent to the complete Defs.h)
 
 // PRINT1 macro from Exercise 1
 #[macro_export]
@@ -20,13 +20,19 @@ macro_rules! print2 {
 }
 
 // MAX2 macro that returns the maximum of two values
+// Works for any PartialOrd type (integers, floats, &str, ...). Ties return
+// the first argument.
+//
+// Unlike the C preprocessor version, each argument is evaluated exactly
+// once: the expressions are bound to locals before comparing, so
+// max2!(next(), next()) calls next() twice, not three times.
 #[macro_export]
 macro_rules! max2 {
     ($x:expr, $y:expr) => {
         {
             let temp_x = $x;
             let temp_y = $y;
-            if temp_x > temp_y { temp_x } else { temp_y }
+            if temp_x >= temp_y { temp_x } else { temp_y }
         }
     };
 }
@@ -39,3 +45,62 @@ macro_rules! max3 {
         max2!(max2!($x, $y), $z)
     };
 }
+
+// MIN2 macro that returns the minimum of two values
+// Same single-evaluation guarantee as MAX2; ties return the first argument
+#[macro_export]
+macro_rules! min2 {
+    ($x:expr, $y:expr) => {
+        {
+            let temp_x = $x;
+            let temp_y = $y;
+            if temp_x <= temp_y { temp_x } else { temp_y }
+        }
+    };
+}
+
+// MIN3 macro that returns the minimum of three values, built on MIN2
+#[macro_export]
+macro_rules! min3 {
+    ($x:expr, $y:expr, $z:expr) => {
+        min2!(min2!($x, $y), $z)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_integers() {
+        assert_eq!(max2!(5, 3), 5);
+        assert_eq!(min2!(5, 3), 3);
+        assert_eq!(max3!(-1, -2, -3), -1);
+        assert_eq!(min3!(2, -7, 1), -7);
+    }
+
+    #[test]
+    fn test_floats() {
+        assert_eq!(max2!(1.5, 2.25), 2.25);
+        assert_eq!(min2!(1.5, 2.25), 1.5);
+        assert_eq!(max3!(0.1, -4.0, 3.5), 3.5);
+        assert_eq!(min3!(0.1, -4.0, 3.5), -4.0);
+    }
+
+    #[test]
+    fn test_strs() {
+        assert_eq!(max2!("apple", "banana"), "banana");
+        assert_eq!(min2!("apple", "banana"), "apple");
+        assert_eq!(max3!("pear", "fig", "kiwi"), "pear");
+        assert_eq!(min3!("pear", "fig", "kiwi"), "fig");
+    }
+
+    #[test]
+    fn test_arguments_evaluated_once() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        assert_eq!(max2!(next(), next()), 2);
+        assert_eq!(calls, 2);
+    }
+}
@@ -68,6 +68,11 @@ fn main() {
     println!("  MAX3(2, 3, 1) = {}", max3!(2, 3, 1));
     println!("  MAX3(-1, -2, -3) = {}", max3!(-1, -2, -3));
     println!("  MAX3(5, 5, 5) = {}", max3!(5, 5, 5));
+    println!();
+    println!("  MIN2(5, 3) = {}", min2!(5, 3));
+    println!("  MIN3(2, 3, 1) = {}", min3!(2, 3, 1));
+    println!("  MAX2(1.5, 2.25) = {}", max2!(1.5, 2.25));
+    println!("  MIN2(\"apple\", \"banana\") = {}", min2!("apple", "banana"));
     
     // Demonstrate with expressions
     println!("\n╔════════════════════════════════════════╗");
@@ -8,24 +8,10 @@
 //
 // Note: In Rust, we use mutable references instead of pointers
 
-use std::io;
-
-// Swap function using mutable references (Rust's equivalent to pointers)
-fn swap(a: &mut i32, b: &mut i32) {
-    let temp = *a;
-    *a = *b;
-    *b = temp;
-}
-
-// Alternative swap using Rust's built-in swap (for comparison)
-fn swap_rust_way(a: &mut i32, b: &mut i32) {
-    std::mem::swap(a, b);
-}
+mod util;
 
-// Another alternative using tuple destructuring
-fn swap_tuple(a: &mut i32, b: &mut i32) {
-    (*a, *b) = (*b, *a);
-}
+use std::io;
+use util::{swap, swap_tuple, swap_with_temp};
 
 fn main() {
     println!("Swap Function Demonstration");
@@ -76,9 +62,9 @@ fn main() {
     swap(&mut x, &mut y);
     println!("After:  x = {}, y = {}", x, y);
     
-    // Swap back using Rust's built-in
-    println!("\nUsing Rust's std::mem::swap:");
-    swap_rust_way(&mut x, &mut y);
+    // Swap back using the classic temporary-variable version
+    println!("\nUsing a temporary variable:");
+    swap_with_temp(&mut x, &mut y);
     println!("After:  x = {}, y = {}", x, y);
     
     // Using tuple destructuring
@@ -118,7 +104,7 @@ fn main() {
     println!("  Dereference: *a = *b");
     println!();
     println!("Rust References:");
-    println!("  fn swap(a: &mut i32, b: &mut i32)");
+    println!("  fn swap<T>(a: &mut T, b: &mut T)");
     println!("  Called with: swap(&mut i, &mut j)");
     println!("  Dereference: *a = *b");
     println!();
@@ -126,5 +112,12 @@ fn main() {
     println!("• Rust references are always valid");
     println!("• No null references in Rust");
     println!("• Borrowing rules prevent data races");
+    println!("• One generic swap<T> works for every type");
+
+    // The generic swap also works for non-Copy types
+    let mut first = String::from("hello");
+    let mut second = String::from("world");
+    swap(&mut first, &mut second);
+    println!("\nswap on Strings: first = {}, second = {}", first, second);
 }
 
@@ -0,0 +1,69 @@
+// Utility Module
+// --------------
+// Reusable helpers split out of the swap exercise so other programs can
+// import them instead of copying the code.
+//
+// C:    void swap(int *a, int *b);       // one function per type
+// Rust: fn swap<T>(a: &mut T, b: &mut T) // one generic function for all types
+
+// Exchange the values of two variables of any type
+// Delegates to std::mem::swap, which moves the bytes without needing
+// T: Copy or T: Clone (so it works for String, Vec, structs, ...)
+pub fn swap<T>(a: &mut T, b: &mut T) {
+    std::mem::swap(a, b);
+}
+
+// Teaching variant: the classic C swap with a temporary variable
+// Only works for Copy types because *a is copied out before being overwritten
+#[allow(clippy::manual_swap)]
+pub fn swap_with_temp(a: &mut i32, b: &mut i32) {
+    let temp = *a;
+    *a = *b;
+    *b = temp;
+}
+
+// Teaching variant: swap through tuple destructuring
+pub fn swap_tuple(a: &mut i32, b: &mut i32) {
+    (*a, *b) = (*b, *a);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn test_swap_i32() {
+        let (mut i, mut j) = (123, 456);
+        swap(&mut i, &mut j);
+        assert_eq!((i, j), (456, 123));
+
+        swap_with_temp(&mut i, &mut j);
+        assert_eq!((i, j), (123, 456));
+        swap_tuple(&mut i, &mut j);
+        assert_eq!((i, j), (456, 123));
+    }
+
+    #[test]
+    fn test_swap_string() {
+        let mut a = String::from("left");
+        let mut b = String::from("right");
+        swap(&mut a, &mut b);
+        assert_eq!(a, "right");
+        assert_eq!(b, "left");
+    }
+
+    #[test]
+    fn test_swap_point() {
+        let mut p = Point { x: 1.0, y: 2.0 };
+        let mut q = Point { x: -3.0, y: 4.5 };
+        swap(&mut p, &mut q);
+        assert_eq!(p, Point { x: -3.0, y: 4.5 });
+        assert_eq!(q, Point { x: 1.0, y: 2.0 });
+    }
+}
@@ -10,7 +10,11 @@
 //
 // Note: In Rust, we'll implement this using slices and references
 
-use std::io::{self, Read};
+#[path = "../../../../Level1/Sect1.4/Exercise1/src/byte_reader.rs"]
+mod byte_reader;
+
+use byte_reader::ByteReader;
+use std::io;
 
 const MAXLINE: usize = 30;
 
@@ -36,6 +40,61 @@ fn length_iter(str: &[u8]) -> usize {
         .count()
 }
 
+// Reusable strlen(): counts BYTES up to (not including) the first NUL.
+// A slice without a NUL is measured to its end instead of overrunning.
+// For UTF-8 text this is the encoded size, e.g. "é" is 2 bytes.
+fn c_strlen(bytes: &[u8]) -> usize {
+    bytes.iter()
+        .position(|&b| b == 0)
+        .unwrap_or(bytes.len())
+}
+
+// Length in CHARACTERS (Unicode scalar values): the UTF-8 aware
+// counterpart of length(). Unlike c_strlen this is independent of the
+// encoded size and does not treat NUL specially, e.g. "é" is 1 character.
+pub fn char_length(s: &str) -> usize {
+    s.chars().count()
+}
+
+// Approximate length in user-perceived characters (graphemes).
+// Proper segmentation needs the Unicode tables (e.g. the
+// unicode-segmentation crate); without extra dependencies this only folds
+// the common cases into the preceding character: combining accents,
+// variation selectors, emoji skin tones and zero-width-joiner sequences.
+// "e" + U+0301 is 1, as is a ZWJ family emoji, but scripts such as Hangul
+// jamo or Indic clusters may still be over-counted.
+pub fn grapheme_length(s: &str) -> usize {
+    let mut count = 0;
+    let mut after_joiner = false;
+
+    for ch in s.chars() {
+        if is_grapheme_extender(ch) {
+            after_joiner = ch == '\u{200D}';
+            continue;
+        }
+        if !after_joiner {
+            count += 1;
+        }
+        after_joiner = false;
+    }
+
+    count
+}
+
+// Characters that attach to the previous one instead of starting a grapheme
+fn is_grapheme_extender(ch: char) -> bool {
+    matches!(ch,
+        '\u{0300}'..='\u{036F}'     // Combining diacritical marks
+        | '\u{1AB0}'..='\u{1AFF}'   // Combining diacritical marks extended
+        | '\u{1DC0}'..='\u{1DFF}'   // Combining diacritical marks supplement
+        | '\u{20D0}'..='\u{20FF}'   // Combining marks for symbols
+        | '\u{FE20}'..='\u{FE2F}'   // Combining half marks
+        | '\u{FE00}'..='\u{FE0F}'   // Variation selectors
+        | '\u{200D}'                 // Zero width joiner
+        | '\u{1F3FB}'..='\u{1F3FF}' // Emoji skin tone modifiers
+    )
+}
+
 fn main() {
     println!("String Length Calculator");
     println!("========================");
@@ -48,16 +107,13 @@ fn main() {
     
     // Get stdin handle for byte-by-byte reading
     let stdin = io::stdin();
-    let mut handle = stdin.lock();
-    let mut buffer = [0u8; 1];
+    let mut reader = ByteReader::new(stdin.lock());
     
     // Read characters until EOF or MAXLINE reached
     while i < MAXLINE {
-        match handle.read(&mut buffer) {
-            Ok(0) => break,  // EOF reached
-            Ok(_) => {
-                let c = buffer[0];
-                
+        match reader.next_byte() {
+            Ok(None) => break,  // EOF reached
+            Ok(Some(c)) => {
                 // Stop at newline or EOF
                 if c == b'\n' {
                     break;
@@ -114,6 +170,19 @@ fn main() {
         println!("Length of \"{}\" = {}", s, len);
     }
     
+    // Bytes vs characters
+    println!("\n╔════════════════════════════════════════╗");
+    println!("║       BYTES VS CHARACTERS              ║");
+    println!("╚════════════════════════════════════════╝");
+    for text in ["Hello", "café", "naïve résumé"] {
+        println!("\"{}\": {} bytes (c_strlen), {} characters (char_length)",
+                 text, c_strlen(text.as_bytes()), char_length(text));
+    }
+    for text in ["cafe\u{0301}", "👍🏽", "👨\u{200D}👩\u{200D}👧"] {
+        println!("\"{}\": {} bytes, {} chars (char_length), ~{} graphemes (grapheme_length)",
+                 text, text.len(), char_length(text), grapheme_length(text));
+    }
+    
     // Show how it works
     println!("\n╔════════════════════════════════════════╗");
     println!("║         HOW LENGTH() WORKS             ║");
@@ -168,3 +237,65 @@ fn main() {
     println!("  }}");
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_strlen_stops_at_nul() {
+        assert_eq!(c_strlen(b"Hello\0"), 5);
+        assert_eq!(c_strlen(b"Hi\0there\0"), 2);
+        assert_eq!(c_strlen(b"\0"), 0);
+        assert_eq!(c_strlen(b""), 0);
+    }
+
+    #[test]
+    fn test_c_strlen_without_nul() {
+        assert_eq!(c_strlen(b"no terminator"), 13);
+    }
+
+    #[test]
+    fn test_c_strlen_matches_length() {
+        let buffer = b"A longer string here\0garbage";
+        assert_eq!(c_strlen(buffer), length(buffer));
+        assert_eq!(c_strlen(buffer), length_iter(buffer));
+    }
+
+    #[test]
+    fn test_multibyte_bytes_vs_chars() {
+        let text = "café";
+        assert_eq!(c_strlen(text.as_bytes()), 5);
+        assert_eq!(char_length(text), 4);
+
+        let greek = "αβγ";
+        assert_eq!(c_strlen(greek.as_bytes()), 6);
+        assert_eq!(char_length(greek), 3);
+    }
+
+    #[test]
+    fn test_ascii_bytes_equal_chars() {
+        let text = "Hello";
+        assert_eq!(c_strlen(text.as_bytes()), char_length(text));
+    }
+
+    #[test]
+    fn test_char_length_vs_bytes() {
+        let text = "é and 😀";
+        assert_eq!(text.len(), 11);       // é is 2 bytes, the emoji 4
+        assert_eq!(c_strlen(text.as_bytes()), 11);
+        assert_eq!(char_length(text), 7);
+    }
+
+    #[test]
+    fn test_grapheme_length() {
+        assert_eq!(grapheme_length("é and 😀"), 7);
+        // Decomposed é: e + combining acute accent
+        assert_eq!(char_length("e\u{0301}"), 2);
+        assert_eq!(grapheme_length("e\u{0301}"), 1);
+        // Thumbs up with a skin tone, and a ZWJ family sequence
+        assert_eq!(grapheme_length("👍🏽"), 1);
+        assert_eq!(char_length("👨\u{200D}👩\u{200D}👧"), 5);
+        assert_eq!(grapheme_length("👨\u{200D}👩\u{200D}👧"), 1);
+        assert_eq!(grapheme_length(""), 0);
+    }
+}
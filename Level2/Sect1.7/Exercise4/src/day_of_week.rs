@@ -0,0 +1,92 @@
+// Day Of Week Module
+// ------------------
+// The seven days as an enum instead of an array of strings, so an invalid
+// day number is caught when converting (from_number returns None) rather
+// than when indexing the array.
+//
+// Numbering follows the exercise: 1 = Sunday ... 7 = Saturday
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOfWeek {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl DayOfWeek {
+    // All days in order, starting with Sunday (day 1)
+    pub const ALL: [DayOfWeek; 7] = [
+        DayOfWeek::Sunday,
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+        DayOfWeek::Saturday,
+    ];
+
+    // Convert a 1-based day number; anything outside 1-7 gives None
+    pub fn from_number(n: u32) -> Option<DayOfWeek> {
+        let index = n.checked_sub(1)? as usize;
+        DayOfWeek::ALL.get(index).copied()
+    }
+
+    // The 1-based day number (Sunday = 1)
+    pub fn to_number(self) -> u32 {
+        self as u32 + 1
+    }
+
+    // The English day name, e.g. "Sunday"
+    pub fn name(self) -> &'static str {
+        match self {
+            DayOfWeek::Sunday => "Sunday",
+            DayOfWeek::Monday => "Monday",
+            DayOfWeek::Tuesday => "Tuesday",
+            DayOfWeek::Wednesday => "Wednesday",
+            DayOfWeek::Thursday => "Thursday",
+            DayOfWeek::Friday => "Friday",
+            DayOfWeek::Saturday => "Saturday",
+        }
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_number() {
+        assert_eq!(DayOfWeek::from_number(1), Some(DayOfWeek::Sunday));
+        assert_eq!(DayOfWeek::from_number(4), Some(DayOfWeek::Wednesday));
+        assert_eq!(DayOfWeek::from_number(7), Some(DayOfWeek::Saturday));
+    }
+
+    #[test]
+    fn test_invalid_numbers() {
+        assert_eq!(DayOfWeek::from_number(0), None);
+        assert_eq!(DayOfWeek::from_number(8), None);
+        assert_eq!(DayOfWeek::from_number(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_round_trip_and_display() {
+        for n in 1..=7 {
+            assert_eq!(DayOfWeek::from_number(n).unwrap().to_number(), n);
+        }
+        assert_eq!(DayOfWeek::Sunday.name(), "Sunday");
+        assert_eq!(DayOfWeek::Saturday.to_string(), "Saturday");
+        assert_eq!(format!("{:<10}|", DayOfWeek::Friday), "Friday    |");
+    }
+}
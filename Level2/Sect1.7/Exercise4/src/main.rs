@@ -9,12 +9,24 @@
 //
 // Note: In Rust, we use an array of &str (string slices)
 
+mod day_of_week;
+
+use day_of_week::DayOfWeek;
 use std::io;
 
 // Function to print the day name for a given day number
+// The lookup goes through DayOfWeek, which rejects numbers outside 1-7
 fn day_name(day_number: i32) {
-    // Array of strings (hard-coded day names)
-    // Index 0 is unused to make days 1-7 map directly
+    let day = u32::try_from(day_number).ok().and_then(DayOfWeek::from_number);
+    match day {
+        Some(day) => println!("Day {} is a {}", day_number, day),
+        None => println!("Error: Invalid day number {}. Please use 1-7.", day_number),
+    }
+}
+
+// Original implementation: hard-coded array of strings
+// Index 0 is unused to make days 1-7 map directly
+fn day_name_array(day_number: i32) {
     let days: [&str; 8] = [
         "",           // Index 0 (unused)
         "Sunday",     // Index 1
@@ -27,7 +39,7 @@ fn day_name(day_number: i32) {
     ];
     
     // Check if day number is valid
-    if day_number >= 1 && day_number <= 7 {
+    if (1..=7).contains(&day_number) {
         println!("Day {} is a {}", day_number, days[day_number as usize]);
     } else {
         println!("Error: Invalid day number {}. Please use 1-7.", day_number);
@@ -47,7 +59,7 @@ fn day_name_zero_based(day_number: i32) {
         "Saturday",
     ];
     
-    if day_number >= 1 && day_number <= 7 {
+    if (1..=7).contains(&day_number) {
         // Subtract 1 to convert 1-7 to 0-6 array indices
         println!("Day {} is a {}", day_number, days[(day_number - 1) as usize]);
     } else {
@@ -122,6 +134,11 @@ fn main() {
     println!("║      ALTERNATIVE IMPLEMENTATIONS       ║");
     println!("╚════════════════════════════════════════╝");
     
+    println!("\nUsing hard-coded array (index 0 unused):");
+    for i in 1..=7 {
+        day_name_array(i);
+    }
+    
     println!("\nUsing zero-based array:");
     for i in 1..=7 {
         day_name_zero_based(i);
@@ -162,11 +179,11 @@ fn main() {
     println!("├─────────┼──────────────┤");
     
     let days: [&str; 8] = ["", "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
-    for i in 0..8 {
+    for (i, day) in days.iter().enumerate() {
         if i == 0 {
-            println!("│ [{}]     │ \"{}\" (unused) │", i, days[i]);
+            println!("│ [{}]     │ \"{}\" (unused) │", i, day);
         } else {
-            println!("│ [{}]     │ \"{:<10}\" │", i, days[i]);
+            println!("│ [{}]     │ \"{:<10}\" │", i, day);
         }
     }
     println!("└─────────┴──────────────┘");
@@ -186,6 +203,10 @@ fn main() {
     println!("3. Match/Switch statement:");
     println!("   - Pro: Very clear and explicit");
     println!("   - Con: More verbose");
+    println!();
+    println!("4. Enum (DayOfWeek):");
+    println!("   - Pro: Invalid days are rejected by from_number()");
+    println!("   - Con: Needs conversions to and from numbers");
     
     // Calendar context
     println!("\n╔════════════════════════════════════════╗");
@@ -196,9 +217,8 @@ fn main() {
     println!("• Monday-start: ISO 8601, most of Europe");
     println!();
     println!("Our mapping (Sunday = 1):");
-    for i in 1..=7 {
-        let days = ["", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
-        print!("{} = {} ", i, days[i]);
+    for day in DayOfWeek::ALL {
+        print!("{} = {} ", day.to_number(), &day.name()[..3]);
     }
     println!();
 }
@@ -16,6 +16,10 @@
 
 use std::fmt;
 
+// Maximum description length in characters (char description[20] in the
+// C version); Article::new and ArticleBuilder::build both count chars
+const MAX_DESCRIPTION_LENGTH: usize = 20;
+
 // Define the Article struct
 #[derive(Debug)]
 struct Article {
@@ -28,11 +32,9 @@ struct Article {
 impl Article {
     // Constructor function to create a new Article
     fn new(article_number: u32, quantity: i32, description: &str) -> Self {
-        // Truncate description to 20 characters if needed
-        let mut desc = description.to_string();
-        if desc.len() > 20 {
-            desc.truncate(20);
-        }
+        // Truncate description to 20 characters if needed; taking whole
+        // chars never splits a multi-byte UTF-8 sequence
+        let desc: String = description.chars().take(MAX_DESCRIPTION_LENGTH).collect();
         
         Article {
             article_number,
@@ -42,6 +44,77 @@ impl Article {
     }
 }
 
+// Reasons ArticleBuilder::build() can reject an Article
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ArticleError {
+    DescriptionTooLong { length: usize },  // Length in characters
+    NegativeQuantity { quantity: i32 },
+}
+
+impl fmt::Display for ArticleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArticleError::DescriptionTooLong { length } => write!(
+                f, "description is {} characters, the maximum is {}",
+                length, MAX_DESCRIPTION_LENGTH
+            ),
+            ArticleError::NegativeQuantity { quantity } => {
+                write!(f, "quantity must not be negative, got {}", quantity)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArticleError {}
+
+// Builder that checks the Article invariants instead of silently fixing them
+// Unset fields default to 0 / an empty description
+#[derive(Debug, Default)]
+struct ArticleBuilder {
+    article_number: u32,
+    quantity: i32,
+    description: String,
+}
+
+impl ArticleBuilder {
+    fn new() -> Self {
+        ArticleBuilder::default()
+    }
+
+    fn article_number(mut self, article_number: u32) -> Self {
+        self.article_number = article_number;
+        self
+    }
+
+    fn quantity(mut self, quantity: i32) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    // Create the Article, or report the first invalid field
+    // Unlike Article::new, an over-long description is an error, not truncated
+    fn build(self) -> Result<Article, ArticleError> {
+        let length = self.description.chars().count();
+        if length > MAX_DESCRIPTION_LENGTH {
+            return Err(ArticleError::DescriptionTooLong { length });
+        }
+        if self.quantity < 0 {
+            return Err(ArticleError::NegativeQuantity { quantity: self.quantity });
+        }
+
+        Ok(Article {
+            article_number: self.article_number,
+            quantity: self.quantity,
+            description: self.description,
+        })
+    }
+}
+
 // Print function that takes a reference to Article (like a pointer in C)
 fn print(article: &Article) {
     println!("╔════════════════════════════════════════╗");
@@ -66,11 +139,12 @@ fn print_verbose(article: &Article) {
     println!("│ Article Number  │ {:20} │", article.article_number);
     println!("│ Quantity        │ {:20} │", article.quantity);
     println!("│ Description     │ {:20} │", article.description);
-    println!("│ Desc. Length    │ {:20} │", article.description.len());
+    println!("│ Desc. Length    │ {:20} │", article.description.chars().count());
     println!("└─────────────────┴──────────────────────┘");
 }
 
 // Function demonstrating manual dereferencing (similar to C's (*p).field)
+#[allow(clippy::explicit_auto_deref)]  // The explicit (*article) is the point here
 fn print_with_deref(article: &Article) {
     println!("\n╔════════════════════════════════════════╗");
     println!("║   MANUAL DEREFERENCE DEMONSTRATION     ║");
@@ -116,6 +190,24 @@ fn main() {
     let article3 = Article::new(3003, 25, "This is a very long description that exceeds twenty characters");
     print_verbose(&article3);
     
+    // Builder: invalid fields are reported instead of silently fixed
+    println!("\n╔════════════════════════════════════════╗");
+    println!("║       VALIDATED BUILDER                ║");
+    println!("╚════════════════════════════════════════╝");
+    
+    let attempts = [
+        ArticleBuilder::new().article_number(6001).quantity(5).description("Tape Measure"),
+        ArticleBuilder::new().article_number(6002).quantity(5)
+            .description("This is a very long description that exceeds twenty characters"),
+        ArticleBuilder::new().article_number(6003).quantity(-3).description("Level"),
+    ];
+    for builder in attempts {
+        match builder.build() {
+            Ok(article) => println!("Built:    {}", article),
+            Err(err) => println!("Rejected: {}", err),
+        }
+    }
+    
     // Demonstrate different print methods
     println!("\n╔════════════════════════════════════════╗");
     println!("║      DIFFERENT ACCESS PATTERNS         ║");
@@ -193,3 +285,55 @@ fn main() {
     println!("Size of Article: {} bytes", std::mem::size_of::<Article>());
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_valid() {
+        let article = ArticleBuilder::new()
+            .article_number(1001)
+            .quantity(50)
+            .description("Hammer")
+            .build()
+            .unwrap();
+
+        assert_eq!(article.article_number, 1001);
+        assert_eq!(article.quantity, 50);
+        assert_eq!(article.description, "Hammer");
+
+        // Exactly 20 characters is allowed
+        let full = ArticleBuilder::new().description("12345678901234567890").build();
+        assert!(full.is_ok());
+    }
+
+    #[test]
+    fn test_builder_description_too_long() {
+        let result = ArticleBuilder::new()
+            .description("This is a very long description")
+            .build();
+        assert_eq!(result.unwrap_err(), ArticleError::DescriptionTooLong { length: 31 });
+
+        // Characters, not bytes: 20 accented letters fit
+        assert!(ArticleBuilder::new().description(&"é".repeat(20)).build().is_ok());
+    }
+
+    #[test]
+    fn test_builder_negative_quantity() {
+        let result = ArticleBuilder::new().quantity(-1).description("Saw").build();
+        let err = result.unwrap_err();
+        assert_eq!(err, ArticleError::NegativeQuantity { quantity: -1 });
+        assert_eq!(err.to_string(), "quantity must not be negative, got -1");
+    }
+
+    #[test]
+    fn test_new_truncates_by_chars() {
+        // 25 two-byte chars: truncating at 20 bytes would keep only 10
+        let article = Article::new(1, 1, &"é".repeat(25));
+        assert_eq!(article.description, "é".repeat(MAX_DESCRIPTION_LENGTH));
+
+        let short = Article::new(1, 1, "Widget");
+        assert_eq!(short.description, "Widget");
+    }
+}
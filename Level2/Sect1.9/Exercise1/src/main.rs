@@ -1,4 +1,8 @@
-use std::io::{self, Read, Write};
+#[path = "../../../../Level1/Sect1.4/Exercise1/src/byte_reader.rs"]
+mod byte_reader;
+
+use byte_reader::ByteReader;
+use std::io::{self, Write};
 
 const CTRL_A: u8 = 1;  // ASCII value of Ctrl+A
 
@@ -17,20 +21,18 @@ fn main() {
     let mut line_count = 0;
     
     // Get stdin handle for byte-by-byte reading
-    let mut handle = stdin.lock();
-    let mut byte_buffer = [0u8; 1];
+    let mut reader = ByteReader::new(stdin.lock());
     
     // Main loop - read characters until Ctrl+A
     loop {
         // Read one character (like getchar())
-        match handle.read(&mut byte_buffer) {
-            Ok(0) => {
+        match reader.next_byte() {
+            Ok(None) => {
                 // EOF reached
                 println!("\nEOF reached. Program ending.");
                 break;
             }
-            Ok(_) => {
-                let ch = byte_buffer[0];
+            Ok(Some(ch)) => {
                 char_count += 1;
                 
                 // Check for Ctrl+A
@@ -82,16 +84,10 @@ fn _c_style_implementation() {
     let stdin = io::stdin();
     let mut stdout = io::stdout();
     let mut buffer: Vec<u8> = Vec::new();
+    let mut reader = ByteReader::new(stdin.lock());
     
-    loop {
-        // Read one byte (like getchar())
-        let mut input = [0u8; 1];
-        if stdin.lock().read(&mut input).unwrap() == 0 {
-            break;
-        }
-        
-        let c = input[0];
-        
+    // Read one byte at a time (like getchar()) until EOF
+    while let Some(c) = reader.next_byte().unwrap() {
         // Check for Ctrl+A
         if c == CTRL_A {
             println!("\nCTRL + A is a correct ending.");
@@ -58,6 +58,11 @@ use paul_lopez::containers::*;
 // 5. Creating shorter alias for YourName::CAD namespace
 // C++: namespace geom = PaulLopez::CAD;
 // Rust: use paul_lopez::cad as geom;
+
+// 6. Prelude with all commonly used types
+// C++: using namespace PaulLopez::CAD; using namespace PaulLopez::Containers;
+// Rust: use paul_lopez::prelude::*;
+// (not imported here, so that the other import styles stay visible)
 use paul_lopez::cad as geom;
 
 fn main() {
@@ -85,11 +90,18 @@ fn main() {
     // C++: using namespace PaulLopez::Containers; then Array<Point> arr(5);
     // Rust: use paul_lopez::containers::*; then Array::with_size(5)
     let mut arr1 = Array::with_size(5); // Array is now directly accessible
-    arr1.set_element(0, paul_lopez::cad::Point::new(10.0, 20.0));
-    arr1.set_element(1, paul_lopez::cad::Point::new(30.0, 40.0));
+    arr1.set_element(0, paul_lopez::cad::Point::new(10.0, 20.0)).expect("index 0 is in bounds");
+    arr1.set_element(1, paul_lopez::cad::Point::new(30.0, 40.0)).expect("index 1 is in bounds");
     println!("Array via namespace using: size = {}", arr1.size());
     println!("  arr1[0]: {}", arr1[0]);
     println!("  arr1[1]: {}", arr1[1]);
+    match arr1.get_element(7) {
+        Some(point) => println!("  arr1.get_element(7): {}", point),
+        None => println!("  arr1.get_element(7): out of bounds"),
+    }
+    if let Err(err) = arr1.set_element(7, paul_lopez::cad::Point::new(0.0, 0.0)) {
+        println!("  arr1.set_element(7, ..): {}", err);
+    }
 
     println!("\n=== 4. Namespace Alias (geom = PaulLopez::CAD) ===");
     // C++: namespace geom = PaulLopez::CAD; then geom::Circle circle(...);
@@ -158,7 +170,7 @@ fn main() {
     }
     
     // Array of Lines
-    let lines = vec![
+    let lines = [
         Line::new(geom::Point::new(0.0, 0.0), geom::Point::new(1.0, 0.0)),
         Line::new(geom::Point::new(0.0, 0.0), geom::Point::new(0.0, 1.0)),
         Line::new(geom::Point::new(0.0, 0.0), geom::Point::new(1.0, 1.0)),
@@ -170,7 +182,7 @@ fn main() {
     }
     
     // Array of Circles
-    let circles = vec![
+    let circles = [
         geom::Circle::new(geom::Point::new(0.0, 0.0), 1.0),
         geom::Circle::new(geom::Point::new(2.0, 2.0), 1.5),
         geom::Circle::new(geom::Point::new(-1.0, 1.0), 0.5),
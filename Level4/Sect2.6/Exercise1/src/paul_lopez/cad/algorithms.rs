@@ -0,0 +1,831 @@
+// Point-set algorithms in CAD namespace - paul_lopez::cad::algorithms
+// ===================================================================
+//
+// Free functions that work on slices of points rather than on a single
+// CAD object, equivalent to a set of C++ <algorithm>-style helpers:
+//
+// C++:  PaulLopez::CAD::Algorithms::SortAngularly(points, center);
+// Rust: paul_lopez::cad::algorithms::sort_angularly(&mut points, &center);
+#![allow(dead_code)]
+
+use super::{Point, Line}; // Use classes from same CAD module
+use super::tolerance;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::f64::consts::PI;
+
+/// Angle of `point` around `center` in the range [0, 2π)
+fn angle_around(point: &Point, center: &Point) -> f64 {
+    let angle = (point.y() - center.y()).atan2(point.x() - center.x());
+    if angle < 0.0 { angle + 2.0 * PI } else { angle }
+}
+
+/// Sort points counterclockwise by their angle around `center`
+///
+/// Angles are measured from the positive x axis, so the first point is the
+/// one closest to "east" of the center. Points at the same angle are
+/// ordered by increasing distance from the center.
+pub fn sort_angularly(points: &mut [Point], center: &Point) {
+    points.sort_by(|a, b| {
+        angle_around(a, center)
+            .total_cmp(&angle_around(b, center))
+            .then(a.distance(center).total_cmp(&b.distance(center)))
+    });
+}
+
+/// Corners (min, max) of the axis-aligned box enclosing all points
+///
+/// Folds the component-wise Point::min and Point::max over the slice.
+/// Returns None for an empty slice. See AABB::from_points for the same
+/// box as an AABB.
+pub fn bounding_box(points: &[Point]) -> Option<(Point, Point)> {
+    let (first, rest) = points.split_first()?;
+    Some(rest.iter().fold((*first, *first), |(lo, hi), p| (lo.min(p), hi.max(p))))
+}
+
+/// Cross product of (a - o) and (b - o); positive for a left turn o -> a -> b
+pub(crate) fn cross(o: &Point, a: &Point, b: &Point) -> f64 {
+    (*a - *o).cross(&(*b - *o))
+}
+
+/// Convex hull using Graham's scan
+///
+/// Sorts the points angularly around the lowest (then leftmost) point and
+/// walks them with a stack, popping every vertex that does not make a
+/// strict left turn. Returns the hull counterclockwise starting at that
+/// lowest point, without collinear vertices. Inputs with fewer than three
+/// distinct points are returned sorted and deduplicated.
+pub fn convex_hull_graham(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.y().total_cmp(&b.y()).then(a.x().total_cmp(&b.x())));
+    sorted.dedup();
+    
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    
+    let pivot = sorted[0];
+    let rest = &mut sorted[1..];
+    sort_angularly(rest, &pivot);
+    
+    let mut hull: Vec<Point> = vec![pivot];
+    for point in rest.iter() {
+        while hull.len() >= 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+    hull
+}
+
+/// Convex hull using Andrew's monotone chain
+///
+/// Sorts the points by (x, y), then builds the lower hull left to right
+/// and the upper hull right to left, popping every vertex that does not
+/// make a strict left turn. Returns the hull counterclockwise starting at
+/// the leftmost (then lowest) point, without collinear vertices, so an
+/// all-collinear input gives just its two extremes. Inputs with fewer than
+/// three distinct points are returned sorted and deduplicated.
+/// O(n log n); the same hull as convex_hull_graham, which starts at the
+/// lowest point instead.
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sort_lexicographically(&mut sorted);
+    sorted.dedup();
+    
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    
+    let mut lower = half_hull(sorted.iter());
+    let mut upper = half_hull(sorted.iter().rev());
+    
+    // Each half ends where the other starts
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// One monotone chain pass: keep only strict left turns
+fn half_hull<'a>(points: impl Iterator<Item = &'a Point>) -> Vec<Point> {
+    let mut chain: Vec<Point> = Vec::new();
+    for point in points {
+        while chain.len() >= 2 && cross(&chain[chain.len() - 2], &chain[chain.len() - 1], point) <= 0.0 {
+            chain.pop();
+        }
+        chain.push(*point);
+    }
+    chain
+}
+
+/// The two closest points and their distance, by brute force
+///
+/// Compares every pair by distance_squared and takes one square root at
+/// the end: O(n²), fine for exercise-sized inputs (divide and conquer
+/// would give O(n log n)). Ties go to the earliest pair in slice order.
+/// Returns None for fewer than two points.
+pub fn closest_pair(points: &[Point]) -> Option<(Point, Point, f64)> {
+    let mut best: Option<(Point, Point, f64)> = None;
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            let distance_squared = a.distance_squared(b);
+            if best.is_none_or(|(_, _, best_squared)| distance_squared < best_squared) {
+                best = Some((*a, *b, distance_squared));
+            }
+        }
+    }
+    best.map(|(a, b, distance_squared)| (a, b, distance_squared.sqrt()))
+}
+
+/// Smooth an open polyline with Chaikin's corner-cutting subdivision
+///
+/// Each iteration replaces every segment P -> Q by the points at 1/4 and
+/// 3/4 along it, keeping the two original endpoints so the curve stays
+/// anchored. A polyline of n points becomes 2n points per iteration.
+/// Inputs with fewer than two points are returned unchanged.
+pub fn chaikin_smooth(points: &[Point], iterations: usize) -> Vec<Point> {
+    let mut current = points.to_vec();
+    if current.len() < 2 {
+        return current;
+    }
+    
+    for _ in 0..iterations {
+        let mut next = Vec::with_capacity(current.len() * 2);
+        next.push(current[0]);
+        for segment in current.windows(2) {
+            let (p, q) = (segment[0], segment[1]);
+            next.push(p * 0.75 + q * 0.25);
+            next.push(p * 0.25 + q * 0.75);
+        }
+        next.push(current[current.len() - 1]);
+        current = next;
+    }
+    current
+}
+
+/// Simplify a polyline with the Ramer-Douglas-Peucker algorithm
+///
+/// Keeps the endpoints and recursively keeps the point farthest from the
+/// current chord whenever it lies more than `epsilon` away, so every
+/// dropped point is within `epsilon` of the simplified polyline.
+pub fn simplify_rdp(points: &[Point], epsilon: f64) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    
+    // Explicit stack of (first, last) index ranges instead of recursion
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let chord = Line::new(points[first], points[last]);
+        let farthest = (first + 1..last)
+            .map(|i| (i, chord.distance_to_point(&points[i])))
+            .max_by(|x, y| x.1.total_cmp(&y.1));
+        
+        if let Some((index, distance)) = farthest {
+            if distance > epsilon {
+                keep[index] = true;
+                ranges.push((first, index));
+                ranges.push((index, last));
+            }
+        }
+    }
+    
+    points.iter()
+        .zip(keep)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+/// Intersection point of two segments, if they cross or touch
+///
+/// Parallel segments (including collinear overlaps, which share infinitely
+/// many points) report no intersection. Parallel is judged relative to the
+/// segment lengths, so rounding noise does not make collinear segments
+/// cross.
+fn segment_intersection(first: &Line, second: &Line) -> Option<Point> {
+    let epsilon = tolerance::default_epsilon();
+    let (a, b) = (first.start(), first.end());
+    let (c, d) = (second.start(), second.end());
+    
+    let (rx, ry) = (b.x() - a.x(), b.y() - a.y());
+    let (sx, sy) = (d.x() - c.x(), d.y() - c.y());
+    let denominator = rx * sy - ry * sx;
+    if denominator.abs() <= epsilon * rx.hypot(ry) * sx.hypot(sy) {
+        return None;
+    }
+    
+    let (qx, qy) = (c.x() - a.x(), c.y() - a.y());
+    let t = (qx * sy - qy * sx) / denominator;
+    let u = (qx * ry - qy * rx) / denominator;
+    
+    let on_segment = |v: f64| (-epsilon..=1.0 + epsilon).contains(&v);
+    if on_segment(t) && on_segment(u) {
+        Some(Point::new(a.x() + t * rx, a.y() + t * ry))
+    } else {
+        None
+    }
+}
+
+/// Sort points by (x, y) so results from different algorithms compare equal
+fn sort_lexicographically(points: &mut [Point]) {
+    points.sort_by(|a, b| a.x().total_cmp(&b.x()).then(a.y().total_cmp(&b.y())));
+}
+
+/// Every pairwise segment intersection by brute force, O(n²)
+///
+/// Reference implementation for all_intersections. One point is reported
+/// per intersecting pair, so three segments through a common point give
+/// three copies of it. Points are sorted by (x, y).
+pub fn all_intersections_naive(segments: &[Line]) -> Vec<Point> {
+    let mut points = Vec::new();
+    for i in 0..segments.len() {
+        for j in (i + 1)..segments.len() {
+            if let Some(p) = segment_intersection(&segments[i], &segments[j]) {
+                points.push(p);
+            }
+        }
+    }
+    sort_lexicographically(&mut points);
+    points
+}
+
+/// Sweep event position: ordered left to right, then bottom to top
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct EventPoint(Point);
+
+impl Eq for EventPoint {}
+
+impl Ord for EventPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.x().total_cmp(&other.0.x()).then(self.0.y().total_cmp(&other.0.y()))
+    }
+}
+
+impl PartialOrd for EventPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Height of an oriented segment where the sweep line through `event` meets it
+///
+/// A vertical segment lies along the sweep line, so it is placed at the
+/// event itself (clamped to its own extent).
+fn sweep_height(&(left, right): &(Point, Point), event: &Point) -> f64 {
+    if left.x() == right.x() {
+        event.y().clamp(left.y(), right.y())
+    } else if event.x() == right.x() {
+        right.y()
+    } else {
+        let slope = (right.y() - left.y()) / (right.x() - left.x());
+        left.y() + (event.x() - left.x()) * slope
+    }
+}
+
+/// Where the sweep handles a crossing of two oriented segments
+///
+/// Rounding can leave a computed crossing just outside the segments'
+/// common x-range (or just off a vertical segment), which would put its
+/// event behind the sweep, so the position is snapped back onto them.
+fn crossing_event(first: &(Point, Point), second: &(Point, Point), crossing: Point) -> Point {
+    for (vertical, other) in [(first, second), (second, first)] {
+        if vertical.0.x() == vertical.1.x() {
+            let x = vertical.0.x();
+            return Point::new(x, sweep_height(other, &Point::new(x, crossing.y())));
+        }
+    }
+    let min_x = first.0.x().max(second.0.x());
+    let max_x = first.1.x().min(second.1.x());
+    Point::new(crossing.x().max(min_x).min(max_x), crossing.y())
+}
+
+/// Order oriented segments by direction, shallowest first and vertical last
+///
+/// This is their bottom-to-top order just right of a point they all pass
+/// through.
+fn compare_direction(&(a, b): &(Point, Point), &(c, d): &(Point, Point)) -> Ordering {
+    let first = (b.y() - a.y()) * (d.x() - c.x());
+    let second = (d.y() - c.y()) * (b.x() - a.x());
+    first.partial_cmp(&second).unwrap_or(Ordering::Equal)
+}
+
+/// Every pairwise segment intersection using a Bentley-Ottmann sweep
+///
+/// A vertical line sweeps left to right over an event queue of segment
+/// endpoints and discovered crossings. The segments it currently cuts are
+/// kept in a status list ordered by their height on the sweep line, and
+/// only segments that become neighbours in that order are tested, so a
+/// crossing is found just before the sweep reaches it. At each event the
+/// segments through it are reported pairwise and reversed in the status
+/// order. O((n + k) log n) queue work for n segments and k crossings; the
+/// status is a sorted Vec, so each update also shifts up to n entries.
+/// Returns exactly what all_intersections_naive returns.
+pub fn all_intersections(segments: &[Line]) -> Vec<Point> {
+    // Orient every segment left to right (bottom to top when vertical)
+    let ends: Vec<(Point, Point)> = segments.iter()
+        .map(|line| {
+            let (a, b) = (*line.start(), *line.end());
+            if EventPoint(a) <= EventPoint(b) { (a, b) } else { (b, a) }
+        })
+        .collect();
+    
+    // Each event lists the segments whose left end it is
+    let mut queue: BTreeMap<EventPoint, Vec<usize>> = BTreeMap::new();
+    for (i, &(left, right)) in ends.iter().enumerate() {
+        // A single point is parallel to everything, so it never intersects
+        if left != right {
+            queue.entry(EventPoint(left)).or_default().push(i);
+            queue.entry(EventPoint(right)).or_default();
+        }
+    }
+    
+    let mut status: Vec<usize> = Vec::new();
+    let mut tested = HashSet::new();
+    let mut points = Vec::new();
+    
+    // Report a pair once; same argument order as the brute force so
+    // results match bit for bit
+    let mut report = |i: usize, j: usize, points: &mut Vec<Point>| {
+        let (first, second) = (i.min(j), i.max(j));
+        if !tested.insert((first, second)) {
+            return None;
+        }
+        let crossing = segment_intersection(&segments[first], &segments[second]);
+        points.extend(crossing);
+        crossing
+    };
+    
+    while let Some((EventPoint(event), starting)) = queue.pop_first() {
+        // The segments through the event form one run of the status list
+        let tolerance = tolerance::default_epsilon() * (1.0 + event.y().abs());
+        let height = |i: usize| sweep_height(&ends[i], &event);
+        let low = status.partition_point(|&i| height(i) < event.y() - tolerance);
+        let high = low + status[low..].partition_point(|&i| height(i) <= event.y() + tolerance);
+        let through: Vec<usize> = status.drain(low..high).collect();
+        
+        let meeting: Vec<usize> = through.iter().chain(&starting).copied().collect();
+        for (k, &i) in meeting.iter().enumerate() {
+            for &j in &meeting[k + 1..] {
+                report(i, j, &mut points);
+            }
+        }
+        
+        // Segments carrying on past the event go back in their new order
+        let mut continuing: Vec<usize> = through.into_iter()
+            .filter(|&i| ends[i].1 != event)
+            .chain(starting)
+            .collect();
+        continuing.sort_by(|&i, &j| compare_direction(&ends[i], &ends[j]).then(i.cmp(&j)));
+        let count = continuing.len();
+        status.splice(low..low, continuing);
+        
+        // Test the newly adjacent pairs at the edges of the run
+        let mut neighbours = Vec::new();
+        if low > 0 && low < status.len() {
+            neighbours.push((status[low - 1], status[low]));
+        }
+        if count > 0 && low + count < status.len() {
+            neighbours.push((status[low + count - 1], status[low + count]));
+        }
+        for (i, j) in neighbours {
+            if let Some(crossing) = report(i, j, &mut points) {
+                let position = crossing_event(&ends[i], &ends[j], crossing);
+                if EventPoint(position) > EventPoint(event) {
+                    queue.entry(EventPoint(position)).or_default();
+                }
+            }
+        }
+    }
+    sort_lexicographically(&mut points);
+    points
+}
+
+/// Density-based clustering (DBSCAN)
+///
+/// A point with at least `min_points` points (itself included) within
+/// `epsilon` is a core point; clusters are grown from core points through
+/// their neighbours. Returns one label per input point: clusters are
+/// numbered 0, 1, 2, ... in order of discovery and noise is labeled -1.
+/// Neighbour queries are brute force, so this is O(n²).
+pub fn dbscan(points: &[Point], epsilon: f64, min_points: usize) -> Vec<i64> {
+    const UNVISITED: i64 = -2;
+    const NOISE: i64 = -1;
+    
+    let neighbours = |i: usize| -> Vec<usize> {
+        (0..points.len())
+            .filter(|&j| points[i].distance(&points[j]) <= epsilon)
+            .collect()
+    };
+    
+    let mut labels = vec![UNVISITED; points.len()];
+    let mut cluster = 0;
+    
+    for i in 0..points.len() {
+        if labels[i] != UNVISITED {
+            continue;
+        }
+        
+        let seeds = neighbours(i);
+        if seeds.len() < min_points {
+            labels[i] = NOISE; // may still become a border point later
+            continue;
+        }
+        
+        labels[i] = cluster;
+        let mut queue = seeds;
+        while let Some(j) = queue.pop() {
+            if labels[j] == NOISE {
+                labels[j] = cluster; // border point
+            }
+            if labels[j] != UNVISITED {
+                continue;
+            }
+            
+            labels[j] = cluster;
+            let reachable = neighbours(j);
+            if reachable.len() >= min_points {
+                queue.extend(reachable); // j is a core point: keep expanding
+            }
+        }
+        cluster += 1;
+    }
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random integer-grid points for hull tests
+    fn seeded_cloud(seed: u64, count: usize) -> Vec<Point> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 33) % 41) as f64 - 20.0
+        };
+        (0..count).map(|_| Point::new(next(), next())).collect()
+    }
+
+    /// Check every point lies on or to the left of every hull edge
+    fn assert_is_convex_hull(hull: &[Point], points: &[Point]) {
+        for i in 0..hull.len() {
+            let (a, b) = (&hull[i], &hull[(i + 1) % hull.len()]);
+            for p in points {
+                assert!(cross(a, b, p) >= 0.0, "{} is outside edge {} -> {}", p, a, b);
+            }
+            // Strict left turns only: no collinear vertices
+            let c = &hull[(i + 2) % hull.len()];
+            assert!(cross(a, b, c) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_sort_angularly_counterclockwise() {
+        let origin = Point::new(0.0, 0.0);
+        let mut points = vec![
+            Point::new(0.0, -1.0),  // 270°
+            Point::new(-1.0, 0.0),  // 180°
+            Point::new(1.0, 1.0),   // 45°
+            Point::new(1.0, 0.0),   // 0°
+            Point::new(-1.0, -1.0), // 225°
+            Point::new(0.0, 2.0),   // 90°
+        ];
+        sort_angularly(&mut points, &origin);
+
+        assert_eq!(points, vec![
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 2.0),
+            Point::new(-1.0, 0.0),
+            Point::new(-1.0, -1.0),
+            Point::new(0.0, -1.0),
+        ]);
+
+        let angles: Vec<f64> = points.iter().map(|p| angle_around(p, &origin)).collect();
+        assert!(angles.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_sort_angularly_ties_by_distance() {
+        let center = Point::new(1.0, 1.0);
+        let mut points = vec![
+            Point::new(4.0, 4.0),
+            Point::new(1.0, 3.0),
+            Point::new(2.0, 2.0),
+            Point::new(3.0, 3.0),
+        ];
+        sort_angularly(&mut points, &center);
+
+        assert_eq!(points, vec![
+            Point::new(2.0, 2.0),
+            Point::new(3.0, 3.0),
+            Point::new(4.0, 4.0),
+            Point::new(1.0, 3.0),
+        ]);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Point::new(1.0, 5.0),
+            Point::new(-2.0, 3.0),
+            Point::new(4.0, -1.0),
+            Point::new(0.5, 0.5),
+        ];
+        assert_eq!(bounding_box(&points), Some((Point::new(-2.0, -1.0), Point::new(4.0, 5.0))));
+        assert_eq!(bounding_box(&points[..1]), Some((points[0], points[0])));
+        assert_eq!(bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn test_graham_square_with_interior_points() {
+        let points = vec![
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0), // on an edge
+            Point::new(0.0, 2.0),
+            Point::new(0.5, 1.5),
+        ];
+
+        assert_eq!(convex_hull_graham(&points), vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+    }
+
+    #[test]
+    fn test_graham_degenerate_inputs() {
+        assert!(convex_hull_graham(&[]).is_empty());
+
+        let p = Point::new(1.0, 2.0);
+        assert_eq!(convex_hull_graham(&[p, p]), vec![p]);
+
+        let collinear: Vec<Point> = (0..5).map(|i| Point::new(i as f64, 2.0 * i as f64)).collect();
+        assert_eq!(convex_hull_graham(&collinear), vec![collinear[0], collinear[4]]);
+    }
+
+    #[test]
+    fn test_graham_seeded_clouds() {
+        for seed in 1..=5 {
+            let points = seeded_cloud(seed, 60);
+            let hull = convex_hull_graham(&points);
+
+            assert!(hull.len() >= 3);
+            assert_is_convex_hull(&hull, &points);
+        }
+    }
+
+    #[test]
+    fn test_monotone_chain_square_with_interior_point() {
+        let points = [
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 1.0), // interior
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0), // on an edge
+        ];
+
+        assert_eq!(convex_hull(&points), vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+    }
+
+    #[test]
+    fn test_monotone_chain_degenerate_inputs() {
+        assert!(convex_hull(&[]).is_empty());
+
+        let (p, q) = (Point::new(3.0, 1.0), Point::new(-1.0, 4.0));
+        assert_eq!(convex_hull(&[p, q, p]), vec![q, p]);
+
+        let collinear: Vec<Point> = (0..5).map(|i| Point::new(2.0 - i as f64, i as f64)).collect();
+        assert_eq!(convex_hull(&collinear), vec![collinear[4], collinear[0]]);
+    }
+
+    #[test]
+    fn test_monotone_chain_matches_graham() {
+        for seed in 1..=20 {
+            let points = seeded_cloud(seed, 60);
+            let mut hull = convex_hull(&points);
+            assert_is_convex_hull(&hull, &points);
+
+            // Same vertices; the two algorithms just start at different points
+            let mut graham = convex_hull_graham(&points);
+            sort_lexicographically(&mut hull);
+            sort_lexicographically(&mut graham);
+            assert_eq!(hull, graham, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_closest_pair() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(5.0, -3.0),
+            Point::new(10.5, 9.5), // close to (10, 10)
+            Point::new(-7.0, 4.0),
+        ];
+        let (a, b, distance) = closest_pair(&points).unwrap();
+        assert_eq!((a, b), (points[1], points[3]));
+        assert!((distance - 0.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_pair_small_inputs() {
+        assert_eq!(closest_pair(&[]), None);
+        assert_eq!(closest_pair(&[Point::new(1.0, 1.0)]), None);
+
+        let (p, q) = (Point::new(0.0, 0.0), Point::new(3.0, 4.0));
+        assert_eq!(closest_pair(&[p, q]), Some((p, q, 5.0)));
+        assert_eq!(closest_pair(&[p, q, p]), Some((p, p, 0.0)));
+    }
+
+    #[test]
+    fn test_chaikin_point_count() {
+        let polyline = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 0.0),
+        ];
+
+        assert_eq!(chaikin_smooth(&polyline, 0), polyline);
+        for iterations in 1..=4 {
+            let smooth = chaikin_smooth(&polyline, iterations);
+            assert_eq!(smooth.len(), polyline.len() << iterations);
+            assert_eq!(smooth[0], polyline[0]);
+            assert_eq!(smooth[smooth.len() - 1], polyline[3]);
+        }
+
+        let first = chaikin_smooth(&polyline, 1);
+        assert_eq!(first[1], Point::new(0.25, 0.5));
+        assert_eq!(first[2], Point::new(0.75, 1.5));
+    }
+
+    #[test]
+    fn test_chaikin_straight_stays_straight() {
+        let a = Point::new(-1.0, 2.0);
+        let b = Point::new(5.0, -1.0);
+        let line = vec![a, (a + b) * 0.5, b];
+
+        for p in chaikin_smooth(&line, 3) {
+            assert!(cross(&a, &b, &p).abs() < 1e-12);
+        }
+        assert_eq!(chaikin_smooth(&[a], 3), vec![a]);
+    }
+
+    #[test]
+    fn test_rdp_collinear_collapses() {
+        let points: Vec<Point> = (0..10).map(|i| Point::new(i as f64, 0.5 * i as f64)).collect();
+
+        assert_eq!(simplify_rdp(&points, 1e-9), vec![points[0], points[9]]);
+    }
+
+    #[test]
+    fn test_rdp_keeps_sharp_corner() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.01),
+            Point::new(2.0, 0.0),
+            Point::new(3.0, 3.0), // corner
+            Point::new(4.0, 0.0),
+            Point::new(5.0, 0.0),
+        ];
+
+        let simplified = simplify_rdp(&points, 0.1);
+        assert_eq!(simplified, vec![points[0], points[2], points[3], points[4], points[5]]);
+
+        // A huge tolerance drops everything but the endpoints
+        assert_eq!(simplify_rdp(&points, 10.0), vec![points[0], points[5]]);
+    }
+
+    #[test]
+    fn test_all_intersections_grid() {
+        // 4 horizontal and 3 vertical segments crossing in a 4 x 3 grid
+        let mut segments = Vec::new();
+        for i in 0..4 {
+            let y = i as f64;
+            segments.push(Line::new(Point::new(-1.0, y), Point::new(3.0, y)));
+        }
+        for i in 0..3 {
+            let x = i as f64;
+            segments.push(Line::new(Point::new(x, -1.0), Point::new(x, 4.0)));
+        }
+
+        let points = all_intersections(&segments);
+        assert_eq!(points.len(), 12);
+        assert_eq!(points[0], Point::new(0.0, 0.0));
+        assert_eq!(points[11], Point::new(2.0, 3.0));
+        assert_eq!(points, all_intersections_naive(&segments));
+    }
+
+    #[test]
+    fn test_all_intersections_disjoint_parallel() {
+        let segments: Vec<Line> = (0..10)
+            .map(|i| Line::new(Point::new(0.0, i as f64), Point::new(5.0, i as f64 + 2.0)))
+            .collect();
+
+        assert!(all_intersections(&segments).is_empty());
+        assert!(all_intersections_naive(&segments).is_empty());
+    }
+
+    #[test]
+    fn test_all_intersections_touching_endpoints() {
+        let segments = vec![
+            Line::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0)),
+            Line::new(Point::new(2.0, 0.0), Point::new(2.0, 2.0)),
+            Line::new(Point::new(5.0, 5.0), Point::new(6.0, 6.0)),
+        ];
+
+        assert_eq!(all_intersections(&segments), vec![Point::new(2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_all_intersections_matches_naive_on_random_segments() {
+        for seed in 1..=5 {
+            let ends = seeded_cloud(seed, 80);
+            let segments: Vec<Line> = ends.chunks(2).map(|c| Line::new(c[0], c[1])).collect();
+
+            assert_eq!(all_intersections(&segments), all_intersections_naive(&segments));
+        }
+    }
+
+    #[test]
+    fn test_all_intersections_vertical_after_rounded_crossing() {
+        // The first crossing rounds to just left of x = 3, behind the event
+        // that inserts the vertical segment; the sweep must still move the
+        // vertical past it to reach the second crossing
+        let segments = [
+            Line::new(Point::new(-10.0, -1.0), Point::new(13.0, 3.0)),
+            Line::new(Point::new(3.0, -8.0), Point::new(3.0, 13.0)),
+            Line::new(Point::new(-6.0, 7.0), Point::new(10.0, 12.0)),
+        ];
+
+        let points = all_intersections(&segments);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points, all_intersections_naive(&segments));
+    }
+
+    #[test]
+    fn test_all_intersections_collinear_disjoint() {
+        // Same line y = x + 10/7, but rounding makes them not quite parallel
+        let segments = [
+            Line::new(Point::new(-2.0 / 7.0, 8.0 / 7.0), Point::new(3.0 / 7.0, 13.0 / 7.0)),
+            Line::new(Point::new(-13.0 / 7.0, -3.0 / 7.0), Point::new(-3.0 / 7.0, 1.0)),
+        ];
+
+        assert!(all_intersections(&segments).is_empty());
+        assert!(all_intersections_naive(&segments).is_empty());
+    }
+
+    #[test]
+    fn test_dbscan_two_blobs_and_outlier() {
+        let mut points = Vec::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                points.push(Point::new(i as f64 * 0.1, j as f64 * 0.1));
+                points.push(Point::new(10.0 + i as f64 * 0.1, 10.0 + j as f64 * 0.1));
+            }
+        }
+        points.push(Point::new(5.0, -5.0)); // outlier
+
+        let labels = dbscan(&points, 0.25, 4);
+
+        let first = labels[0];
+        let second = labels[1];
+        assert!(first >= 0 && second >= 0);
+        assert_ne!(first, second);
+        for (i, label) in labels.iter().enumerate().take(18) {
+            assert_eq!(*label, if i % 2 == 0 { first } else { second });
+        }
+        assert_eq!(labels[18], -1);
+    }
+
+    #[test]
+    fn test_dbscan_border_point_and_empty() {
+        // Three core points and one border point reachable from them only
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.5, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+        ];
+
+        assert_eq!(dbscan(&points, 1.0, 3), vec![0, 0, 0, 0]);
+        assert_eq!(dbscan(&points, 0.1, 2), vec![-1, -1, -1, -1]);
+        assert!(dbscan(&[], 1.0, 3).is_empty());
+    }
+}
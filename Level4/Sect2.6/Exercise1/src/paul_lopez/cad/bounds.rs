@@ -0,0 +1,194 @@
+// Bounding boxes in CAD namespace - paul_lopez::cad::{AABB, BoundsBuilder}
+// =========================================================================
+#![allow(dead_code)]
+
+use super::Point; // Use Point from same CAD module
+use std::fmt;
+
+/// Axis-aligned bounding box
+///
+/// Stores the lower-left (min) and upper-right (max) corners
+/// Located in the paul_lopez::cad namespace
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AABB {
+    min: Point,
+    max: Point,
+}
+
+impl AABB {
+    /// Create a box from two opposite corners (in any order)
+    pub fn new(a: Point, b: Point) -> Self {
+        AABB { min: a.min(&b), max: a.max(&b) }
+    }
+
+    /// Compute the box enclosing all points in a slice
+    /// Returns None for an empty slice
+    pub fn from_points(points: &[Point]) -> Option<AABB> {
+        let mut builder = BoundsBuilder::new();
+        for point in points {
+            builder.add(point);
+        }
+        builder.finish()
+    }
+
+    /// Get the lower-left corner
+    pub fn min(&self) -> &Point {
+        &self.min
+    }
+
+    /// Get the upper-right corner
+    pub fn max(&self) -> &Point {
+        &self.max
+    }
+
+    /// Get the width of the box
+    pub fn width(&self) -> f64 {
+        self.max.x() - self.min.x()
+    }
+
+    /// Get the height of the box
+    pub fn height(&self) -> f64 {
+        self.max.y() - self.min.y()
+    }
+
+    /// Check if a point is inside or on the edge of the box
+    pub fn contains_point(&self, point: &Point) -> bool {
+        point.x() >= self.min.x() && point.x() <= self.max.x()
+            && point.y() >= self.min.y() && point.y() <= self.max.y()
+    }
+
+    /// Check if two boxes overlap (touching edges count)
+    pub fn intersects(&self, other: &AABB) -> bool {
+        self.min.x() <= other.max.x() && other.min.x() <= self.max.x()
+            && self.min.y() <= other.max.y() && other.min.y() <= self.max.y()
+    }
+
+    /// Grow the box so that it also covers the given point
+    pub fn expand_to(&mut self, point: &Point) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+}
+
+impl fmt::Display for AABB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AABB[min: {}, max: {}]", self.min, self.max)
+    }
+}
+
+/// Incremental bounding box accumulator
+///
+/// Computes the bounding box of a stream of points one at a time,
+/// so the points never have to be collected into a container first.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BoundsBuilder {
+    bounds: Option<AABB>,
+}
+
+impl BoundsBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        BoundsBuilder { bounds: None }
+    }
+
+    /// Add a point to the accumulated bounds
+    pub fn add(&mut self, p: &Point) {
+        match self.bounds.as_mut() {
+            Some(bounds) => bounds.expand_to(p),
+            None => self.bounds = Some(AABB::new(*p, *p)),
+        }
+    }
+
+    /// Finish accumulating and return the box
+    /// Returns None if no points were added
+    pub fn finish(self) -> Option<AABB> {
+        self.bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paul_lopez::cad::algorithms::bounding_box;
+
+    #[test]
+    fn test_aabb_normalizes_corners() {
+        let a = AABB::new(Point::new(3.0, 1.0), Point::new(1.0, 4.0));
+
+        assert_eq!(*a.min(), Point::new(1.0, 1.0));
+        assert_eq!(*a.max(), Point::new(3.0, 4.0));
+        assert_eq!(a.width(), 2.0);
+        assert_eq!(a.height(), 3.0);
+        assert!(a.contains_point(&Point::new(2.0, 2.0)));
+        assert!(!a.contains_point(&Point::new(0.0, 2.0)));
+    }
+
+    #[test]
+    fn test_builder_matches_batch() {
+        let points = [
+            Point::new(1.0, 5.0),
+            Point::new(-2.0, 3.0),
+            Point::new(4.0, -1.0),
+            Point::new(0.5, 0.5),
+        ];
+
+        let mut builder = BoundsBuilder::new();
+        for p in &points {
+            builder.add(p);
+        }
+        let streamed = builder.finish().unwrap();
+
+        // Reference box from a plain fold over the coordinates, which shares
+        // no code with BoundsBuilder (from_points is built on the builder)
+        let (lo, hi) = points.iter().fold(
+            ((f64::INFINITY, f64::INFINITY), (f64::NEG_INFINITY, f64::NEG_INFINITY)),
+            |((min_x, min_y), (max_x, max_y)), p| {
+                ((min_x.min(p.x()), min_y.min(p.y())), (max_x.max(p.x()), max_y.max(p.y())))
+            },
+        );
+        let expected = AABB::new(Point::new(lo.0, lo.1), Point::new(hi.0, hi.1));
+
+        assert_eq!(streamed, expected);
+        assert_eq!(AABB::from_points(&points), Some(expected));
+        assert_eq!(
+            bounding_box(&points),
+            Some((*streamed.min(), *streamed.max()))
+        );
+        assert_eq!(*streamed.min(), Point::new(-2.0, -1.0));
+        assert_eq!(*streamed.max(), Point::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn test_builder_single_and_empty() {
+        let mut builder = BoundsBuilder::new();
+        builder.add(&Point::new(2.0, 3.0));
+        let bounds = builder.finish().unwrap();
+        assert_eq!(*bounds.min(), *bounds.max());
+
+        assert_eq!(BoundsBuilder::new().finish(), None);
+        assert_eq!(AABB::from_points(&[]), None);
+    }
+
+    #[test]
+    fn test_builder_from_lazy_iterator() {
+        let mut builder = BoundsBuilder::new();
+        (0..100)
+            .map(|i| Point::new(i as f64, (i as f64).sin()))
+            .for_each(|p| builder.add(&p));
+        let bounds = builder.finish().unwrap();
+
+        assert_eq!(bounds.min().x(), 0.0);
+        assert_eq!(bounds.max().x(), 99.0);
+        assert!(bounds.height() <= 2.0);
+    }
+
+    #[test]
+    fn test_aabb_intersects() {
+        let a = AABB::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+
+        assert!(a.intersects(&AABB::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0))));
+        assert!(a.intersects(&AABB::new(Point::new(2.0, 0.0), Point::new(3.0, 1.0))));
+        assert!(!a.intersects(&AABB::new(Point::new(2.5, 0.0), Point::new(3.0, 1.0))));
+    }
+}
@@ -3,12 +3,38 @@
 #![allow(dead_code)]
 
 use super::Point; // Use Point from same CAD module
+use super::tolerance;
+use super::json::JsonObject;
+use crate::paul_lopez::error::GeometryError;
+use super::traits::{Drawable, Measurable, Scalable, Shape, Translatable};
 use std::fmt;
 
+/// How two circles are positioned relative to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircleRelation {
+    /// No common points, neither inside the other
+    Separate,
+    /// Touching from the outside at a single point
+    ExternallyTangent,
+    /// Boundaries cross at two points
+    Intersecting,
+    /// Touching from the inside at a single point
+    InternallyTangent,
+    /// The other circle lies strictly inside this one
+    Contains,
+    /// This circle lies strictly inside the other one
+    ContainedBy,
+    /// Same center and radius
+    Identical,
+}
+
 /// Circle class representing a circle in 2D space
 /// 
 /// This represents a circle defined by a center point and radius
 /// Located in the paul_lopez::cad namespace
+///
+/// `==` compares the center and radius exactly; use approx_eq for
+/// computed values.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Circle {
     center: Point,
@@ -31,6 +57,31 @@ impl Circle {
         Circle::new(Point::default(), radius)
     }
     
+    /// Create the circle having segment a-b as its diameter
+    pub fn from_diameter(a: Point, b: Point) -> Self {
+        Circle::new(a.midpoint(&b), a.distance(&b) / 2.0)
+    }
+    
+    /// Create the circle passing through three points (the circumcircle)
+    /// Returns None if the points are collinear (or coincide)
+    pub fn from_three_points(a: Point, b: Point, c: Point) -> Option<Self> {
+        // Work relative to a to keep the numbers small
+        let ab = b - a;
+        let ac = c - a;
+        let (ab_sq, ac_sq) = (ab.distance_to_origin_squared(), ac.distance_to_origin_squared());
+        
+        let d = 2.0 * (ab.x() * ac.y() - ab.y() * ac.x());
+        if d.abs() <= 1e-12 * (ab_sq + ac_sq) {
+            return None;
+        }
+        
+        let offset = Point::new(
+            (ac.y() * ab_sq - ab.y() * ac_sq) / d,
+            (ab.x() * ac_sq - ac.x() * ab_sq) / d
+        );
+        Some(Circle::new(a + offset, offset.distance_to_origin()))
+    }
+    
     /// Get the center point
     pub fn center(&self) -> &Point {
         &self.center
@@ -66,23 +117,40 @@ impl Circle {
         2.0 * std::f64::consts::PI * self.radius
     }
     
-    /// Check if a point is inside the circle
+    /// Calculate the area of a sector spanning `angle` radians
+    /// The sign of the angle is ignored; angles past 2π are not wrapped
+    pub fn sector_area(&self, angle: f64) -> f64 {
+        0.5 * self.radius * self.radius * angle.abs()
+    }
+    
+    /// Calculate the length of an arc spanning `angle` radians
+    /// The sign of the angle is ignored; angles past 2π are not wrapped
+    pub fn arc_length(&self, angle: f64) -> f64 {
+        self.radius * angle.abs()
+    }
+    
+    /// Check if a point is inside or on the boundary of the circle
     pub fn contains_point(&self, point: &Point) -> bool {
         self.center.distance(point) <= self.radius
     }
     
     /// Check if a point is on the circle boundary (within epsilon)
     pub fn point_on_boundary(&self, point: &Point) -> bool {
-        const EPSILON: f64 = 1e-10;
-        (self.center.distance(point) - self.radius).abs() < EPSILON
+        (self.center.distance(point) - self.radius).abs() < tolerance::default_epsilon()
     }
     
     /// Get a point on the circle at given angle (in radians)
     pub fn point_at_angle(&self, angle: f64) -> Point {
-        Point::new(
-            self.center.x() + self.radius * angle.cos(),
-            self.center.y() + self.radius * angle.sin()
-        )
+        self.center + Point::from_polar(self.radius, angle)
+    }
+    
+    /// Sample `count` evenly spaced points on the circumference,
+    /// counterclockwise starting at angle 0
+    pub fn sample_points(&self, count: usize) -> Vec<Point> {
+        let step = 2.0 * std::f64::consts::PI / count as f64;
+        (0..count)
+            .map(|i| self.point_at_angle(i as f64 * step))
+            .collect()
     }
     
     /// Move the circle by given offset
@@ -90,12 +158,50 @@ impl Circle {
         Circle::new(self.center.translate(dx, dy), self.radius)
     }
     
-    /// Scale the circle by given factor
-    pub fn scale(&self, factor: f64) -> Circle {
-        Circle::new(self.center, self.radius * factor)
+    /// Copy with the radius scaled by `factor`, center unchanged
+    /// A negative factor gives a positive radius (|factor| is used); unlike
+    /// Scalable::scale, which scales about the origin and moves the center
+    pub fn scaled(&self, factor: f64) -> Circle {
+        Circle::new(self.center, self.radius * factor.abs())
+    }
+    
+    /// Copy moved by the given offset, radius unchanged (same as translate)
+    pub fn translated(&self, dx: f64, dy: f64) -> Circle {
+        self.translate(dx, dy)
+    }
+    
+    /// Rasterize the boundary onto the integer grid (midpoint circle algorithm)
+    ///
+    /// The center and radius are rounded to whole cells. Each boundary cell
+    /// is listed once, sorted by (x, y); a radius below 0.5 gives just the
+    /// center cell.
+    pub fn rasterize_circle(&self) -> Vec<(i64, i64)> {
+        let (cx, cy) = (self.center.x().round() as i64, self.center.y().round() as i64);
+        let r = self.radius.abs().round() as i64;
+        
+        // Walk one octant from (r, 0) and mirror it into the other seven
+        let mut cells = Vec::new();
+        let (mut x, mut y, mut err) = (r, 0, 1 - r);
+        while x >= y {
+            for (dx, dy) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+                cells.push((cx + dx, cy + dy));
+            }
+            y += 1;
+            if err < 0 {
+                err += 2 * y + 1;
+            } else {
+                x -= 1;
+                err += 2 * (y - x) + 1;
+            }
+        }
+        
+        cells.sort_unstable();
+        cells.dedup();
+        cells
     }
     
-    /// Get the bounding box of the circle
+    /// Get the bounding box of the circle as (lower-left, upper-right)
+    /// A zero radius gives a degenerate box with both corners at the center
     pub fn bounding_box(&self) -> (Point, Point) {
         let min_point = Point::new(
             self.center.x() - self.radius,
@@ -108,6 +214,31 @@ impl Circle {
         (min_point, max_point)
     }
     
+    /// Serialize as a JSON object: `{"center":{...},"radius":5.0}`
+    pub fn to_json(self) -> String {
+        format!("{{\"center\":{},\"radius\":{:?}}}", self.center.to_json(), self.radius)
+    }
+    
+    /// Parse a circle from the JSON written by to_json
+    pub fn from_json(json: &str) -> Result<Circle, GeometryError> {
+        let object = JsonObject::parse(json)?;
+        let center = Point::from_json(object.object("center")?)?;
+        Ok(Circle::new(center, object.number("radius")?))
+    }
+    
+    /// Render as an SVG `<circle>` element
+    pub fn to_svg(self) -> String {
+        format!(
+            "<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\"/>",
+            self.center.x(), self.center.y(), self.radius
+        )
+    }
+    
+    /// Check if the centers and the radii each differ by at most `epsilon`
+    pub fn approx_eq(&self, other: &Circle, epsilon: f64) -> bool {
+        self.center.approx_eq(&other.center, epsilon) && (self.radius - other.radius).abs() <= epsilon
+    }
+    
     /// Check if this circle intersects with another circle
     pub fn intersects(&self, other: &Circle) -> bool {
         let center_distance = self.center.distance(&other.center);
@@ -116,6 +247,119 @@ impl Circle {
         
         center_distance >= radius_diff && center_distance <= radius_sum
     }
+    
+    /// Classify how this circle relates to another circle
+    pub fn classify(&self, other: &Circle) -> CircleRelation {
+        let epsilon = tolerance::default_epsilon();
+        let center_distance = self.center.distance(&other.center);
+        let radius_sum = self.radius + other.radius;
+        let radius_diff = (self.radius - other.radius).abs();
+        
+        if center_distance < epsilon && radius_diff < epsilon {
+            CircleRelation::Identical
+        } else if (center_distance - radius_sum).abs() < epsilon {
+            CircleRelation::ExternallyTangent
+        } else if center_distance > radius_sum {
+            CircleRelation::Separate
+        } else if (center_distance - radius_diff).abs() < epsilon {
+            CircleRelation::InternallyTangent
+        } else if center_distance < radius_diff {
+            if self.radius > other.radius {
+                CircleRelation::Contains
+            } else {
+                CircleRelation::ContainedBy
+            }
+        } else {
+            CircleRelation::Intersecting
+        }
+    }
+    
+    /// Area of the lens where two circles overlap
+    ///
+    /// Zero for separate or externally tangent circles, the smaller circle's
+    /// area when one lies inside the other (tangent from the inside
+    /// included), and otherwise the sum of the two circular segments cut
+    /// off by the common chord.
+    pub fn overlap_area(&self, other: &Circle) -> f64 {
+        let (r1, r2) = (self.radius, other.radius);
+        let d = self.center.distance(&other.center);
+        
+        if d >= r1 + r2 {
+            return 0.0;
+        }
+        if d <= (r1 - r2).abs() {
+            let r = r1.min(r2);
+            return std::f64::consts::PI * r * r;
+        }
+        
+        // Half-angles subtended by the common chord at each center
+        // (clamped: rounding can push the cosines just past ±1)
+        let alpha = ((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1)).clamp(-1.0, 1.0).acos();
+        let beta = ((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2)).clamp(-1.0, 1.0).acos();
+        
+        // Sectors minus the kite formed by the two centers and the chord ends
+        let kite = 0.5 * ((-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2)).sqrt();
+        r1 * r1 * alpha + r2 * r2 * beta - kite
+    }
+    
+    /// Find the points where the boundaries of two circles cross
+    ///
+    /// Returns two points for intersecting circles, one for tangent
+    /// circles and none otherwise (including identical circles, which
+    /// share infinitely many points).
+    pub fn intersection_points(&self, other: &Circle) -> Vec<Point> {
+        let relation = self.classify(other);
+        match relation {
+            CircleRelation::Separate
+            | CircleRelation::Contains
+            | CircleRelation::ContainedBy
+            | CircleRelation::Identical => return Vec::new(),
+            _ => {}
+        }
+        
+        let dx = other.center.x() - self.center.x();
+        let dy = other.center.y() - self.center.y();
+        let d = (dx * dx + dy * dy).sqrt();
+        let (ux, uy) = (dx / d, dy / d);
+        
+        // Distance from this center to the chord, along the center line
+        let a = (self.radius * self.radius - other.radius * other.radius + d * d) / (2.0 * d);
+        let base = Point::new(self.center.x() + a * ux, self.center.y() + a * uy);
+        
+        match relation {
+            CircleRelation::Intersecting => {
+                let h = (self.radius * self.radius - a * a).max(0.0).sqrt();
+                vec![
+                    Point::new(base.x() - h * uy, base.y() + h * ux),
+                    Point::new(base.x() + h * uy, base.y() - h * ux),
+                ]
+            }
+            _ => vec![base],
+        }
+    }
+    
+    /// Smallest radius of a circle that touches both circles from outside
+    ///
+    /// Half the gap between the two boundaries, or zero when the circles
+    /// already touch or overlap.
+    pub fn externally_tangent_radius(&self, other: &Circle) -> f64 {
+        let gap = self.center.distance(&other.center) - self.radius - other.radius;
+        (gap / 2.0).max(0.0)
+    }
+    
+    /// Circles of the given radius externally tangent to both `a` and `b`
+    ///
+    /// The centers are the intersections of `a` and `b` grown by `radius`,
+    /// so there are 0, 1 or 2 solutions.
+    pub fn tangent_to_both(a: &Circle, b: &Circle, radius: f64) -> Vec<Circle> {
+        let grown_a = Circle::new(a.center, a.radius + radius);
+        let grown_b = Circle::new(b.center, b.radius + radius);
+        
+        grown_a.intersection_points(&grown_b)
+            .into_iter()
+            .map(|center| Circle::new(center, radius))
+            .collect()
+    }
 }
 
 impl Default for Circle {
@@ -124,6 +368,45 @@ impl Default for Circle {
     }
 }
 
+impl Measurable for Circle {
+    fn extent(&self) -> f64 {
+        self.circumference()
+    }
+}
+
+impl Drawable for Circle {
+    fn raster_cells(&self) -> Vec<(i64, i64)> {
+        self.rasterize_circle()
+    }
+}
+
+/// The center scales about the pivot and the radius by |factor|
+impl Scalable for Circle {
+    fn scale_about(&self, pivot: &Point, factor: f64) -> Circle {
+        Circle::new(self.center.scale_about(pivot, factor), self.radius * factor.abs())
+    }
+}
+
+impl Translatable for Circle {
+    fn translate(&self, dx: f64, dy: f64) -> Circle {
+        Circle::translate(self, dx, dy)
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        Circle::area(self)
+    }
+    
+    fn perimeter(&self) -> f64 {
+        self.circumference()
+    }
+    
+    fn name(&self) -> &str {
+        "Circle"
+    }
+}
+
 impl fmt::Display for Circle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Circle[center: {}, radius: {:.2}]", self.center, self.radius)
@@ -156,11 +439,27 @@ mod tests {
     fn test_point_containment() {
         let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
         
-        assert!(circle.contains_point(&Point::new(3.0, 4.0))); // Inside
+        assert!(circle.contains_point(&Point::new(3.0, 3.0))); // Inside
+        assert!(circle.contains_point(&Point::new(3.0, 4.0))); // On boundary (distance 5)
         assert!(circle.contains_point(&Point::new(5.0, 0.0))); // On boundary
         assert!(!circle.contains_point(&Point::new(6.0, 0.0))); // Outside
     }
 
+    #[test]
+    fn test_contains_point_center_and_offset() {
+        let circle = Circle::new(Point::new(-2.0, 1.0), 2.5);
+        
+        assert!(circle.contains_point(circle.center()));
+        assert!(circle.contains_point(&Point::new(-2.0, 3.5))); // On boundary
+        assert!(circle.contains_point(&Point::new(-0.5, 3.0))); // On boundary (3-4-5 scaled)
+        assert!(!circle.contains_point(&Point::new(0.0, 3.0)));
+        
+        // A zero-radius circle contains only its center
+        let dot = Circle::new(Point::new(1.0, 1.0), 0.0);
+        assert!(dot.contains_point(&Point::new(1.0, 1.0)));
+        assert!(!dot.contains_point(&Point::new(1.0, 1.0 + 1e-9)));
+    }
+
     #[test]
     fn test_point_at_angle() {
         let circle = Circle::unit_circle();
@@ -182,7 +481,7 @@ mod tests {
         assert_eq!(*translated.center(), Point::new(5.0, 7.0));
         assert_eq!(translated.radius(), 3.0);
         
-        let scaled = circle.scale(2.0);
+        let scaled = circle.scaled(2.0);
         assert_eq!(*scaled.center(), Point::new(1.0, 2.0));
         assert_eq!(scaled.radius(), 6.0);
     }
@@ -196,6 +495,15 @@ mod tests {
         assert_eq!(max, Point::new(3.0, 4.0));
     }
 
+    #[test]
+    fn test_bounding_box_zero_radius() {
+        let center = Point::new(-1.5, 4.0);
+        let (min, max) = Circle::new(center, 0.0).bounding_box();
+        
+        assert_eq!(min, center);
+        assert_eq!(max, center);
+    }
+
     #[test]
     fn test_circle_intersection() {
         let c1 = Circle::new(Point::new(0.0, 0.0), 3.0);
@@ -205,4 +513,262 @@ mod tests {
         assert!(c1.intersects(&c2));
         assert!(!c1.intersects(&c3));
     }
+
+    #[test]
+    fn test_classify() {
+        let base = Circle::new(Point::new(0.0, 0.0), 3.0);
+        
+        let cases = [
+            (Circle::new(Point::new(10.0, 0.0), 1.0), CircleRelation::Separate),
+            (Circle::new(Point::new(5.0, 0.0), 2.0), CircleRelation::ExternallyTangent),
+            (Circle::new(Point::new(4.0, 0.0), 2.0), CircleRelation::Intersecting),
+            (Circle::new(Point::new(1.0, 0.0), 2.0), CircleRelation::InternallyTangent),
+            (Circle::new(Point::new(0.5, 0.0), 1.0), CircleRelation::Contains),
+            (Circle::new(Point::new(1.0, 1.0), 10.0), CircleRelation::ContainedBy),
+            (Circle::new(Point::new(0.0, 0.0), 3.0), CircleRelation::Identical),
+        ];
+        
+        for (other, expected) in cases {
+            assert_eq!(base.classify(&other), expected, "classifying {}", other);
+        }
+    }
+
+    #[test]
+    fn test_classify_concentric() {
+        let outer = Circle::new(Point::new(2.0, 2.0), 5.0);
+        let inner = Circle::new(Point::new(2.0, 2.0), 1.0);
+        
+        assert_eq!(outer.classify(&inner), CircleRelation::Contains);
+        assert_eq!(inner.classify(&outer), CircleRelation::ContainedBy);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let circle = Circle::new(Point::new(1.0, -2.0), 5.0);
+        
+        assert_eq!(circle.to_json(), r#"{"center":{"x":1.0,"y":-2.0},"radius":5.0}"#);
+        assert_eq!(Circle::from_json(&circle.to_json()), Ok(circle));
+    }
+
+    #[test]
+    fn test_json_malformed() {
+        assert_eq!(
+            Circle::from_json(r#"{"center":{"x":1.0,"y":-2.0}}"#),
+            Err(GeometryError::ParseError("missing field \"radius\"".to_string()))
+        );
+        assert!(Circle::from_json(r#"{"center":{"x":1.0,"y":-2.0},"radius":{"x":1}}"#).is_err());
+        assert!(Circle::from_json(r#"{"center":{"x":1.0,"y":-2.0},"radius":5.0"#).is_err());
+    }
+
+    #[test]
+    fn test_intersection_points() {
+        let a = Circle::new(Point::new(0.0, 0.0), 5.0);
+        let b = Circle::new(Point::new(6.0, 0.0), 5.0);
+        
+        let points = a.intersection_points(&b);
+        assert_eq!(points, vec![Point::new(3.0, 4.0), Point::new(3.0, -4.0)]);
+        
+        let touching = Circle::new(Point::new(10.0, 0.0), 5.0);
+        assert_eq!(a.intersection_points(&touching), vec![Point::new(5.0, 0.0)]);
+        
+        let inner = Circle::new(Point::new(2.0, 0.0), 3.0);
+        assert_eq!(a.intersection_points(&inner), vec![Point::new(5.0, 0.0)]);
+        
+        assert!(a.intersection_points(&Circle::new(Point::new(20.0, 0.0), 1.0)).is_empty());
+        assert!(a.intersection_points(&a).is_empty());
+    }
+
+    #[test]
+    fn test_tangent_to_both_two_solutions() {
+        let a = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let b = Circle::new(Point::new(6.0, 0.0), 1.0);
+        
+        let tangent = Circle::tangent_to_both(&a, &b, 4.0);
+        assert_eq!(tangent.len(), 2);
+        for circle in &tangent {
+            assert_eq!(circle.radius(), 4.0);
+            assert_eq!(circle.classify(&a), CircleRelation::ExternallyTangent);
+            assert_eq!(circle.classify(&b), CircleRelation::ExternallyTangent);
+        }
+        assert_eq!(*tangent[0].center(), Point::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn test_tangent_to_both_none_and_minimum() {
+        let a = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let b = Circle::new(Point::new(6.0, 0.0), 1.0);
+        
+        assert_eq!(a.externally_tangent_radius(&b), 2.0);
+        assert!(Circle::tangent_to_both(&a, &b, 1.0).is_empty());
+        
+        // Exactly the minimum radius fits in the gap once
+        let single = Circle::tangent_to_both(&a, &b, 2.0);
+        assert_eq!(single, vec![Circle::new(Point::new(3.0, 0.0), 2.0)]);
+        
+        let overlapping = Circle::new(Point::new(1.0, 0.0), 1.0);
+        assert_eq!(a.externally_tangent_radius(&overlapping), 0.0);
+    }
+
+    #[test]
+    fn test_from_diameter() {
+        let circle = Circle::from_diameter(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+        
+        assert_eq!(*circle.center(), Point::new(2.0, 0.0));
+        assert_eq!(circle.radius(), 2.0);
+    }
+
+    #[test]
+    fn test_from_three_points() {
+        let known = Circle::new(Point::new(1.0, -2.0), 3.0);
+        let a = known.point_at_angle(0.3);
+        let b = known.point_at_angle(2.0);
+        let c = known.point_at_angle(4.5);
+        
+        let circle = Circle::from_three_points(a, b, c).unwrap();
+        assert!(circle.center().approx_eq(known.center(), 1e-10));
+        assert!((circle.radius() - 3.0).abs() < 1e-10);
+        
+        // Right triangle: the hypotenuse is a diameter
+        let right = Circle::from_three_points(
+            Point::new(0.0, 0.0), Point::new(6.0, 0.0), Point::new(0.0, 8.0)
+        ).unwrap();
+        assert_eq!(*right.center(), Point::new(3.0, 4.0));
+        assert_eq!(right.radius(), 5.0);
+    }
+
+    #[test]
+    fn test_from_three_points_collinear() {
+        let a = Point::new(0.0, 0.0);
+        
+        assert_eq!(Circle::from_three_points(a, Point::new(1.0, 1.0), Point::new(2.0, 2.0)), None);
+        assert_eq!(Circle::from_three_points(a, Point::new(0.1, 0.3), Point::new(0.2, 0.6)), None);
+        assert_eq!(Circle::from_three_points(a, a, a), None);
+    }
+
+    #[test]
+    fn test_sector_area_and_arc_length() {
+        let circle = Circle::new(Point::new(1.0, 1.0), 3.0);
+        let full = 2.0 * std::f64::consts::PI;
+        
+        assert!((circle.sector_area(full) - circle.area()).abs() < 1e-12);
+        assert!((circle.arc_length(full) - circle.circumference()).abs() < 1e-12);
+        assert_eq!(circle.sector_area(1.0), 4.5);
+        assert_eq!(circle.arc_length(0.5), 1.5);
+        
+        // Negative angles measure the same sector in the other direction
+        assert_eq!(circle.sector_area(-1.0), circle.sector_area(1.0));
+        assert_eq!(circle.arc_length(-0.5), circle.arc_length(0.5));
+        assert_eq!(circle.diameter(), 6.0);
+    }
+
+    #[test]
+    fn test_point_at_angle_off_origin() {
+        let circle = Circle::new(Point::new(2.0, -1.0), 3.0);
+        
+        assert!(circle.point_at_angle(0.0).approx_eq(&Point::new(5.0, -1.0), 1e-10));
+        assert!(circle.point_at_angle(std::f64::consts::FRAC_PI_2).approx_eq(&Point::new(2.0, 2.0), 1e-10));
+    }
+
+    #[test]
+    fn test_sample_points() {
+        let circle = Circle::new(Point::new(2.0, -1.0), 3.0);
+        let points = circle.sample_points(4);
+        
+        let expected = [
+            Point::new(5.0, -1.0),
+            Point::new(2.0, 2.0),
+            Point::new(-1.0, -1.0),
+            Point::new(2.0, -4.0),
+        ];
+        assert_eq!(points.len(), 4);
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert!(p.approx_eq(e, 1e-10));
+        }
+        
+        for p in circle.sample_points(37) {
+            assert!(circle.point_on_boundary(&p));
+        }
+        assert!(circle.sample_points(0).is_empty());
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = Circle::new(Point::new(10.0, 20.0), 5.5).to_svg();
+        assert_eq!(svg, r#"<circle cx="10.00" cy="20.00" r="5.50"/>"#);
+    }
+
+    #[test]
+    fn test_scaled_and_translated() {
+        let circle = Circle::new(Point::new(1.0, -1.0), 2.0);
+
+        let scaled = circle.scaled(1.5);
+        assert_eq!(scaled.radius(), 3.0);
+        assert_eq!(*scaled.center(), Point::new(1.0, -1.0));
+        assert_eq!(circle.scaled(-1.5).radius(), 3.0);
+
+        let moved = circle.translated(2.0, 3.0);
+        assert_eq!(*moved.center(), Point::new(3.0, 2.0));
+        assert_eq!(moved.radius(), 2.0);
+    }
+
+    #[test]
+    fn test_equality() {
+        let circle = Circle::new(Point::new(1.0, 2.0), 3.0);
+        assert_eq!(circle, Circle::new(Point::new(1.0, 2.0), 3.0));
+        assert_ne!(circle, Circle::new(Point::new(1.0, 2.0), 3.5));
+
+        let nudged = Circle::new(Point::new(1.0 + 1e-10, 2.0), 3.0 - 1e-10);
+        assert_ne!(circle, nudged);
+        assert!(circle.approx_eq(&nudged, 1e-9));
+        assert!(!circle.approx_eq(&nudged, 1e-11));
+    }
+
+    #[test]
+    fn test_rasterize_circle() {
+        let cells = Circle::new(Point::new(0.0, 0.0), 1.0).rasterize_circle();
+        assert_eq!(cells, vec![(-1, 0), (0, -1), (0, 1), (1, 0)]);
+
+        let cells = Circle::new(Point::new(10.2, -3.0), 5.0).rasterize_circle();
+        // Every cell is within half a cell of the true boundary
+        for &(x, y) in &cells {
+            let d = ((x - 10) as f64).hypot((y + 3) as f64);
+            assert!((d - 5.0).abs() <= 0.5 + 1e-9, "({}, {}) is {} from the center", x, y, d);
+        }
+        assert!(cells.contains(&(15, -3)) && cells.contains(&(10, 2)));
+
+        assert_eq!(Circle::new(Point::new(2.0, 3.0), 0.2).rasterize_circle(), vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_overlap_area_extremes() {
+        let circle = Circle::new(Point::new(1.0, 2.0), 3.0);
+        assert_eq!(circle.overlap_area(&circle), circle.area());
+
+        let far = Circle::new(Point::new(20.0, 2.0), 3.0);
+        assert_eq!(circle.overlap_area(&far), 0.0);
+
+        // Touching from outside, and nested
+        let touching = Circle::new(Point::new(7.0, 2.0), 3.0);
+        assert_eq!(circle.overlap_area(&touching), 0.0);
+        let inner = Circle::new(Point::new(2.0, 2.0), 1.0);
+        assert_eq!(circle.overlap_area(&inner), inner.area());
+        assert_eq!(inner.overlap_area(&circle), inner.area());
+    }
+
+    #[test]
+    fn test_overlap_area_partial() {
+        // Two unit circles one radius apart: 2π/3 - √3/2
+        let a = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let b = Circle::new(Point::new(1.0, 0.0), 1.0);
+        let expected = 2.0 * std::f64::consts::PI / 3.0 - 3.0_f64.sqrt() / 2.0;
+        assert!((a.overlap_area(&b) - expected).abs() < 1e-12);
+        assert!((b.overlap_area(&a) - expected).abs() < 1e-12);
+
+        // Unequal radii stay between the extremes and are symmetric
+        let big = Circle::new(Point::new(0.0, 0.0), 2.0);
+        let small = Circle::new(Point::new(2.0, 0.5), 1.0);
+        let overlap = big.overlap_area(&small);
+        assert!(overlap > 0.0 && overlap < small.area());
+        assert!((overlap - small.overlap_area(&big)).abs() < 1e-12);
+    }
 }
@@ -0,0 +1,176 @@
+// Minimal JSON reader for the CAD types - paul_lopez::cad::json
+// =============================================================
+//
+// Just enough JSON to read back what the to_json methods write: a single
+// object whose values are numbers or nested objects. Nested objects are
+// kept as raw text so each type can hand them to the matching from_json.
+// Kept dependency-free on purpose.
+#![allow(dead_code)]
+
+use crate::paul_lopez::error::GeometryError;
+
+/// A field value: a number or the raw text of a nested object
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum JsonValue<'a> {
+    Number(f64),
+    Object(&'a str),
+}
+
+/// A parsed JSON object as an ordered list of fields
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonObject<'a> {
+    fields: Vec<(&'a str, JsonValue<'a>)>,
+}
+
+impl<'a> JsonObject<'a> {
+    /// Parse a complete JSON object; trailing content is an error
+    pub(crate) fn parse(input: &'a str) -> Result<Self, GeometryError> {
+        let mut cursor = Cursor { text: input, pos: 0 };
+        let object = cursor.parse_object()?;
+        cursor.skip_whitespace();
+        if cursor.pos != input.len() {
+            return Err(GeometryError::ParseError(format!("unexpected trailing input at position {}", cursor.pos)));
+        }
+        Ok(object)
+    }
+
+    /// Look up a numeric field
+    pub(crate) fn number(&self, key: &str) -> Result<f64, GeometryError> {
+        match self.get(key)? {
+            JsonValue::Number(value) => Ok(value),
+            JsonValue::Object(_) => Err(GeometryError::ParseError(format!("field \"{}\" is not a number", key))),
+        }
+    }
+
+    /// Look up a nested object field, returning its raw text
+    pub(crate) fn object(&self, key: &str) -> Result<&'a str, GeometryError> {
+        match self.get(key)? {
+            JsonValue::Object(raw) => Ok(raw),
+            JsonValue::Number(_) => Err(GeometryError::ParseError(format!("field \"{}\" is not an object", key))),
+        }
+    }
+
+    fn get(&self, key: &str) -> Result<JsonValue<'a>, GeometryError> {
+        self.fields.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| GeometryError::ParseError(format!("missing field \"{}\"", key)))
+    }
+}
+
+struct Cursor<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), GeometryError> {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(GeometryError::ParseError(format!("expected '{}' at position {}", byte as char, self.pos)))
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonObject<'a>, GeometryError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonObject { fields });
+        }
+
+        loop {
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            let value = self.parse_value()?;
+            fields.push((key, value));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonObject { fields });
+                }
+                _ => return Err(GeometryError::ParseError(format!("expected ',' or '}}' at position {}", self.pos))),
+            }
+        }
+    }
+
+    // Keys never need escapes, so a backslash is rejected rather than decoded
+    fn parse_string(&mut self) -> Result<&'a str, GeometryError> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => return Err(GeometryError::ParseError(format!("escape sequences are not supported (position {})", self.pos))),
+                Some(_) => self.pos += 1,
+                None => return Err(GeometryError::ParseError("unterminated string".to_string())),
+            }
+        }
+        let value = &self.text[start..self.pos];
+        self.pos += 1;
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue<'a>, GeometryError> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if self.peek() == Some(b'{') {
+            // Validate the nested object now but hand back its raw text
+            self.parse_object()?;
+            return Ok(JsonValue::Object(&self.text[start..self.pos]));
+        }
+
+        while matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')) {
+            self.pos += 1;
+        }
+        let literal = &self.text[start..self.pos];
+        literal.parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| GeometryError::ParseError(format!("invalid number at position {}", start)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flat_and_nested() {
+        let object = JsonObject::parse(r#" { "a" : 1.5, "b": {"c": -2e3} } "#).unwrap();
+
+        assert_eq!(object.number("a"), Ok(1.5));
+        assert_eq!(object.object("b"), Ok(r#"{"c": -2e3}"#));
+        assert_eq!(JsonObject::parse(object.object("b").unwrap()).unwrap().number("c"), Ok(-2000.0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(JsonObject::parse("").is_err());
+        assert!(JsonObject::parse(r#"{"a": 1"#).is_err());
+        assert!(JsonObject::parse(r#"{"a": 1} x"#).is_err());
+        assert!(JsonObject::parse(r#"{"a": abc}"#).is_err());
+
+        let object = JsonObject::parse(r#"{"a": 1}"#).unwrap();
+        assert_eq!(object.number("b"), Err(GeometryError::ParseError("missing field \"b\"".to_string())));
+        assert!(object.object("a").is_err());
+    }
+}
@@ -2,12 +2,20 @@
 // ==================================================
 #![allow(dead_code)]
 use super::Point; // Use Point from same CAD module (relative import)
+use super::Circle;
+use super::tolerance;
+use super::json::JsonObject;
+use crate::paul_lopez::error::GeometryError;
+use super::traits::{Drawable, Measurable, Scalable, Shape, Translatable};
 use std::fmt;
 
 /// Line class representing a line segment between two points
 /// 
 /// This represents a line segment in 2D space defined by start and end points
 /// Located in the paul_lopez::cad namespace
+///
+/// `==` compares the endpoints exactly and in order, so a line never
+/// equals its reverse; use approx_eq for computed coordinates.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Line {
     start: Point,
@@ -35,6 +43,13 @@ impl Line {
         Line::new(Point::new(0.0, 0.0), Point::new(0.0, length))
     }
     
+    /// Create the segment of y = slope * x + intercept between two x values
+    /// Equal x values give a zero-length line (and so no slope to recover)
+    pub fn from_slope_intercept(slope: f64, intercept: f64, x_start: f64, x_end: f64) -> Self {
+        let y_at = |x: f64| slope * x + intercept;
+        Line::new(Point::new(x_start, y_at(x_start)), Point::new(x_end, y_at(x_end)))
+    }
+    
     /// Get the start point
     pub fn start(&self) -> &Point {
         &self.start
@@ -57,12 +72,34 @@ impl Line {
     
     /// Calculate the length of the line
     pub fn length(&self) -> f64 {
-        self.start.distance(&self.end)
+        self.length_squared().sqrt()
+    }
+    
+    /// Area of a line segment: always zero (it encloses nothing)
+    /// Lets a Line be treated like the closed shapes, e.g. via the Shape trait
+    pub fn area(&self) -> f64 {
+        0.0
+    }
+    
+    /// Perimeter of a line segment: its length
+    pub fn perimeter(&self) -> f64 {
+        self.length()
+    }
+    
+    /// Calculate the squared length (no square root; enough for comparisons)
+    pub fn length_squared(&self) -> f64 {
+        (self.end - self.start).distance_to_origin_squared()
     }
     
     /// Get the midpoint of the line
     pub fn midpoint(&self) -> Point {
-        (self.start + self.end) * 0.5
+        self.start.midpoint(&self.end)
+    }
+    
+    /// Evaluate the line parametrically: t = 0 gives start, t = 1 gives end
+    /// Values outside [0, 1] extrapolate beyond the endpoints
+    pub fn point_at(&self, t: f64) -> Point {
+        self.start.lerp(&self.end, t)
     }
     
     /// Get the slope of the line (rise/run)
@@ -78,6 +115,107 @@ impl Line {
         }
     }
     
+    /// Get the y value where the (extended) line crosses the y axis
+    /// Returns None for vertical lines
+    pub fn y_intercept(&self) -> Option<f64> {
+        self.slope().map(|m| self.start.y() - m * self.start.x())
+    }
+    
+    /// Find where this line crosses another, treating both as infinite lines
+    /// Returns None for parallel (including coincident) lines
+    pub fn intersection(&self, other: &Line) -> Option<Point> {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let denominator = d1.cross(&d2);
+        if denominator == 0.0 {
+            return None;
+        }
+        
+        let t = (other.start - self.start).cross(&d2) / denominator;
+        Some(self.start + d1 * t)
+    }
+    
+    /// Unit direction vectors of both lines, or None if either has zero length
+    fn unit_directions(&self, other: &Line) -> Option<(Point, Point)> {
+        Some((self.direction()?, other.direction()?))
+    }
+    
+    /// Check if two lines run in the same (or opposite) direction
+    ///
+    /// Compares the sine of the angle between them against `epsilon`, so the
+    /// tolerance does not depend on the line lengths. Zero-length lines have
+    /// no direction and are never parallel.
+    pub fn is_parallel(&self, other: &Line, epsilon: f64) -> bool {
+        self.unit_directions(other)
+            .is_some_and(|(u1, u2)| u1.cross(&u2).abs() <= epsilon)
+    }
+    
+    /// Check if two lines meet at a right angle
+    ///
+    /// Compares the cosine of the angle between them against `epsilon`.
+    /// Zero-length lines are never perpendicular.
+    pub fn is_perpendicular(&self, other: &Line, epsilon: f64) -> bool {
+        self.unit_directions(other)
+            .is_some_and(|(u1, u2)| u1.dot(&u2).abs() <= epsilon)
+    }
+    
+    /// Shortest distance from a point to this line, treated as infinite
+    /// A zero-length line falls back to the distance to its start point
+    pub fn distance_to_point(&self, p: &Point) -> f64 {
+        let length = self.length();
+        if length == 0.0 {
+            return self.start.distance(p);
+        }
+        (self.end - self.start).cross(&(*p - self.start)).abs() / length
+    }
+    
+    /// Foot of the perpendicular from a point onto this line (treated as infinite)
+    /// A zero-length line returns its start point
+    pub fn closest_point(&self, p: &Point) -> Point {
+        let direction = self.end - self.start;
+        let length_squared = self.length_squared();
+        if length_squared == 0.0 {
+            return self.start;
+        }
+        let t = (*p - self.start).dot(&direction) / length_squared;
+        self.start + direction * t
+    }
+    
+    /// Find where this segment crosses a circle's boundary
+    ///
+    /// Returns up to two points ordered from start to end. A tangent
+    /// segment gives one point; a segment that stops short of the circle
+    /// returns nothing even if its infinite extension would hit it.
+    pub fn intersects_circle(&self, circle: &Circle) -> Vec<Point> {
+        let epsilon = tolerance::default_epsilon();
+        
+        let direction = match self.direction() {
+            Some(direction) => direction,
+            None if circle.point_on_boundary(&self.start) => return vec![self.start],
+            None => return Vec::new(),
+        };
+        
+        // Drop a perpendicular from the center, then step along the line
+        let foot = self.closest_point(circle.center());
+        let gap = circle.center().distance(&foot);
+        let candidates = if (gap - circle.radius()).abs() < epsilon {
+            vec![foot]
+        } else if gap > circle.radius() {
+            Vec::new()
+        } else {
+            let half_chord = (circle.radius() * circle.radius() - gap * gap).sqrt();
+            vec![foot - direction * half_chord, foot + direction * half_chord]
+        };
+        
+        let length = self.length();
+        candidates.into_iter()
+            .filter(|p| {
+                let along = (*p - self.start).dot(&direction);
+                (-epsilon..=length + epsilon).contains(&along)
+            })
+            .collect()
+    }
+    
     /// Check if the line is horizontal
     pub fn is_horizontal(&self) -> bool {
         (self.start.y() - self.end.y()).abs() < f64::EPSILON
@@ -88,6 +226,31 @@ impl Line {
         (self.start.x() - self.end.x()).abs() < f64::EPSILON
     }
     
+    /// Get the unit vector pointing from start to end
+    /// Returns None for a zero-length line
+    pub fn direction(&self) -> Option<Point> {
+        (self.end - self.start).normalized()
+    }
+    
+    /// Get the same line running from end to start
+    pub fn reversed(&self) -> Line {
+        Line::new(self.end, self.start)
+    }
+    
+    /// Get the perpendicular bisector: the segment rotated a quarter turn
+    /// counterclockwise about its midpoint, so it has the same length
+    /// Returns None for a zero-length line, which has no direction
+    pub fn perpendicular_bisector(&self) -> Option<Line> {
+        if self.start == self.end {
+            return None;
+        }
+        
+        let mid = self.midpoint();
+        let half = self.end - mid;
+        let normal = Point::new(-half.y(), half.x());
+        Some(Line::new(mid - normal, mid + normal))
+    }
+    
     /// Get the angle of the line in radians
     pub fn angle(&self) -> f64 {
         let dx = self.end.x() - self.start.x();
@@ -102,8 +265,25 @@ impl Line {
         let line_length = self.length();
         
         // Point is on line if sum of distances equals line length
-        const EPSILON: f64 = 1e-10;
-        (dist_to_start + dist_to_end - line_length).abs() < EPSILON
+        (dist_to_start + dist_to_end - line_length).abs() < tolerance::default_epsilon()
+    }
+    
+    /// Check if a point is within `epsilon` of this finite segment
+    ///
+    /// Unlike distance_to_point, the line is not extended: the foot of the
+    /// perpendicular is clamped to the segment (t in [0, 1]), so a collinear
+    /// point beyond an end is off the segment while the endpoints are on
+    /// it. Named apart from contains_point, which uses the global default
+    /// epsilon and a sum-of-distances test; this method takes an explicit
+    /// epsilon and clamps to the segment.
+    pub fn contains_point_on_segment(&self, p: &Point, epsilon: f64) -> bool {
+        let length_squared = self.length_squared();
+        if length_squared == 0.0 {
+            return self.start.distance(p) <= epsilon;
+        }
+        
+        let t = ((*p - self.start).dot(&(self.end - self.start)) / length_squared).clamp(0.0, 1.0);
+        self.point_at(t).distance(p) <= epsilon
     }
     
     /// Translate the line by given offset
@@ -124,6 +304,109 @@ impl Line {
         
         self.translate(offset_x, offset_y)
     }
+    
+    /// Serialize as a JSON object: `{"start":{...},"end":{...}}`
+    pub fn to_json(self) -> String {
+        format!("{{\"start\":{},\"end\":{}}}", self.start.to_json(), self.end.to_json())
+    }
+    
+    /// Parse a line from the JSON written by to_json
+    pub fn from_json(json: &str) -> Result<Line, GeometryError> {
+        let object = JsonObject::parse(json)?;
+        let start = Point::from_json(object.object("start")?)?;
+        let end = Point::from_json(object.object("end")?)?;
+        Ok(Line::new(start, end))
+    }
+    
+    /// Render as an SVG `<line>` element
+    pub fn to_svg(self) -> String {
+        format!(
+            "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\"/>",
+            self.start.x(), self.start.y(), self.end.x(), self.end.y()
+        )
+    }
+    
+    /// Check if both endpoints (in order) are within `epsilon`, per coordinate
+    pub fn approx_eq(&self, other: &Line, epsilon: f64) -> bool {
+        self.start.approx_eq(&other.start, epsilon) && self.end.approx_eq(&other.end, epsilon)
+    }
+    
+    /// Tessellate a circular arc from `start` to `end` into a polyline
+    ///
+    /// `bulge` is the tangent of a quarter of the included angle, as used by
+    /// DXF polylines: 0 is a straight segment, 1 is a counterclockwise
+    /// semicircle and negative values bend clockwise. The result contains
+    /// `segments + 1` points (0 segments is treated as 1) and always begins
+    /// and ends exactly at the inputs. A straight arc (zero bulge or
+    /// coincident endpoints) is just the two endpoints.
+    pub fn arc_between(start: &Point, end: &Point, bulge: f64, segments: usize) -> Vec<Point> {
+        let segments = segments.max(1);
+        let dx = end.x() - start.x();
+        let dy = end.y() - start.y();
+        let chord = (dx * dx + dy * dy).sqrt();
+        
+        if bulge == 0.0 || chord == 0.0 {
+            return vec![*start, *end];
+        }
+        
+        // Center sits on the chord's perpendicular bisector, offset to the
+        // left of start->end by chord * (1 - b^2) / (4b)
+        let offset = chord * (1.0 - bulge * bulge) / (4.0 * bulge);
+        let center = Point::new(
+            (start.x() + end.x()) / 2.0 - dy / chord * offset,
+            (start.y() + end.y()) / 2.0 + dx / chord * offset
+        );
+        
+        let radius = center.distance(start);
+        let start_angle = (start.y() - center.y()).atan2(start.x() - center.x());
+        let sweep = 4.0 * bulge.atan();
+        
+        let mut points = Vec::with_capacity(segments + 1);
+        points.push(*start);
+        for i in 1..segments {
+            let angle = start_angle + sweep * (i as f64 / segments as f64);
+            points.push(Point::new(
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin()
+            ));
+        }
+        points.push(*end);
+        points
+    }
+    
+    /// Rasterize the line onto the integer grid using Bresenham's algorithm
+    ///
+    /// Endpoints are rounded to the nearest cell; the result lists every cell
+    /// from start to end inclusive, with exactly one cell per step along the
+    /// major axis.
+    pub fn rasterize_line(&self) -> Vec<(i64, i64)> {
+        let (mut x, mut y) = (self.start.x().round() as i64, self.start.y().round() as i64);
+        let (x1, y1) = (self.end.x().round() as i64, self.end.y().round() as i64);
+        
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        
+        let mut cells = Vec::with_capacity((dx.max(-dy) + 1) as usize);
+        loop {
+            cells.push((x, y));
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+        cells
+    }
 }
 
 impl Default for Line {
@@ -132,6 +415,44 @@ impl Default for Line {
     }
 }
 
+impl Measurable for Line {
+    fn extent(&self) -> f64 {
+        self.length()
+    }
+}
+
+impl Drawable for Line {
+    fn raster_cells(&self) -> Vec<(i64, i64)> {
+        self.rasterize_line()
+    }
+}
+
+impl Scalable for Line {
+    fn scale_about(&self, pivot: &Point, factor: f64) -> Line {
+        Line::new(self.start.scale_about(pivot, factor), self.end.scale_about(pivot, factor))
+    }
+}
+
+impl Translatable for Line {
+    fn translate(&self, dx: f64, dy: f64) -> Line {
+        Line::translate(self, dx, dy)
+    }
+}
+
+impl Shape for Line {
+    fn area(&self) -> f64 {
+        Line::area(self)
+    }
+    
+    fn perimeter(&self) -> f64 {
+        Line::perimeter(self)
+    }
+    
+    fn name(&self) -> &str {
+        "Line"
+    }
+}
+
 impl fmt::Display for Line {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Line[{} -> {}]", self.start, self.end)
@@ -215,4 +536,376 @@ mod tests {
         let distance_check = parallel.start().distance(line.start());
         assert!((distance_check - 1.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_arc_between_zero_bulge() {
+        let start = Point::new(0.0, 0.0);
+        let end = Point::new(3.0, 4.0);
+        let arc = Line::arc_between(&start, &end, 0.0, 8);
+        
+        assert_eq!(arc, vec![start, end]);
+    }
+
+    #[test]
+    fn test_arc_between_zero_segments() {
+        let start = Point::new(1.0, 0.0);
+        let end = Point::new(-1.0, 0.0);
+        
+        // Same as a single segment: segments + 1 == 2 points
+        let arc = Line::arc_between(&start, &end, 1.0, 0);
+        assert_eq!(arc, Line::arc_between(&start, &end, 1.0, 1));
+        assert_eq!(arc, vec![start, end]);
+    }
+
+    #[test]
+    fn test_arc_between_semicircle() {
+        let start = Point::new(0.0, 0.0);
+        let end = Point::new(2.0, 0.0);
+        let arc = Line::arc_between(&start, &end, 1.0, 16);
+        
+        assert_eq!(arc.len(), 17);
+        assert_eq!(arc[0], start);
+        assert_eq!(arc[16], end);
+        
+        // Every sample lies on the circle with the chord as diameter
+        let center = Point::new(1.0, 0.0);
+        for p in &arc {
+            assert!((center.distance(p) - 1.0).abs() < 1e-10);
+        }
+        
+        // Positive bulge runs counterclockwise, so the arc dips below the chord
+        assert!((arc[8].x() - 1.0).abs() < 1e-10);
+        assert!((arc[8].y() + 1.0).abs() < 1e-10);
+        
+        // Negative bulge mirrors it above the chord
+        let mirrored = Line::arc_between(&start, &end, -1.0, 16);
+        assert!((mirrored[8].y() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_arc_between_quarter_circle() {
+        // Quarter circle of radius 1 centred on the origin
+        let start = Point::new(1.0, 0.0);
+        let end = Point::new(0.0, 1.0);
+        let bulge = (std::f64::consts::PI / 8.0).tan();
+        let arc = Line::arc_between(&start, &end, bulge, 4);
+        
+        for p in &arc {
+            assert!((p.distance_to_origin() - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_rasterize_horizontal() {
+        let line = Line::new(Point::new(0.0, 2.0), Point::new(4.0, 2.0));
+        
+        assert_eq!(line.rasterize_line(), vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+        
+        // Reversed direction walks the same cells backwards
+        let mut cells = line.reversed().rasterize_line();
+        cells.reverse();
+        assert_eq!(cells, line.rasterize_line());
+    }
+
+    #[test]
+    fn test_rasterize_diagonal() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(3.0, 3.0));
+        
+        assert_eq!(line.rasterize_line(), vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_rasterize_steep() {
+        // Endpoints round to (0, 0) and (2, 5)
+        let line = Line::new(Point::new(0.2, -0.3), Point::new(1.6, 5.4));
+        let cells = line.rasterize_line();
+        
+        assert_eq!(cells, vec![(0, 0), (0, 1), (1, 2), (1, 3), (2, 4), (2, 5)]);
+        
+        // One cell per row, each step moves at most one column
+        for pair in cells.windows(2) {
+            assert_eq!(pair[1].1 - pair[0].1, 1);
+            assert!((pair[1].0 - pair[0].0).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_rasterize_degenerate() {
+        let line = Line::new(Point::new(1.4, 1.4), Point::new(0.6, 0.6));
+        
+        assert_eq!(line.rasterize_line(), vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(-3.5, 4.25));
+        
+        assert_eq!(line.to_json(), r#"{"start":{"x":1.0,"y":2.0},"end":{"x":-3.5,"y":4.25}}"#);
+        assert_eq!(Line::from_json(&line.to_json()), Ok(line));
+    }
+
+    #[test]
+    fn test_json_malformed() {
+        assert_eq!(
+            Line::from_json(r#"{"start":{"x":1.0,"y":2.0}}"#),
+            Err(GeometryError::ParseError("missing field \"end\"".to_string()))
+        );
+        assert!(Line::from_json(r#"{"start":1.0,"end":{"x":0,"y":0}}"#).is_err());
+        assert!(Line::from_json(r#"{"start":{"x":1.0},"end":{"x":0,"y":0}}"#).is_err());
+    }
+
+    #[test]
+    fn test_slope_and_y_intercept() {
+        let diagonal = Line::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert_eq!(diagonal.slope(), Some(1.0));
+        assert_eq!(diagonal.y_intercept(), Some(0.0));
+        
+        let shifted = Line::new(Point::new(2.0, 1.0), Point::new(4.0, 0.0));
+        assert_eq!(shifted.slope(), Some(-0.5));
+        assert_eq!(shifted.y_intercept(), Some(2.0));
+        
+        let vertical = Line::new(Point::new(2.0, 0.0), Point::new(2.0, 5.0));
+        assert_eq!(vertical.slope(), None);
+        assert_eq!(vertical.y_intercept(), None);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let horizontal = Line::new(Point::new(0.0, 2.0), Point::new(1.0, 2.0));
+        let vertical = Line::new(Point::new(3.0, -1.0), Point::new(3.0, 0.0));
+        
+        // Infinite lines: the crossing lies outside both segments
+        assert_eq!(horizontal.intersection(&vertical), Some(Point::new(3.0, 2.0)));
+        assert_eq!(vertical.intersection(&horizontal), Some(Point::new(3.0, 2.0)));
+        
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 4.0));
+        let b = Line::new(Point::new(0.0, 4.0), Point::new(4.0, 0.0));
+        assert_eq!(a.intersection(&b), Some(Point::new(2.0, 2.0)));
+    }
+
+    #[test]
+    fn test_intersection_parallel() {
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0));
+        let b = a.translate(0.0, 3.0);
+        
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(a.intersection(&a), None);
+    }
+
+    #[test]
+    fn test_parallel_and_perpendicular() {
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0));
+        let b = Line::new(Point::new(5.0, 5.0), Point::new(1.0, 3.0)); // opposite direction
+        let c = Line::new(Point::new(1.0, 1.0), Point::new(0.0, 3.0));
+        
+        assert!(a.is_parallel(&b, 1e-12));
+        assert!(!a.is_perpendicular(&b, 1e-12));
+        assert!(a.is_perpendicular(&c, 1e-12));
+        assert!(!a.is_parallel(&c, 1e-12));
+        
+        let point = Line::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0));
+        assert!(!a.is_parallel(&point, 1.0));
+        assert!(!a.is_perpendicular(&point, 1.0));
+    }
+
+    #[test]
+    fn test_near_parallel_needs_loose_epsilon() {
+        let a = Line::horizontal(10.0);
+        let b = Line::new(Point::new(0.0, 1.0), Point::new(10.0, 1.0001));
+        
+        assert!(!a.is_parallel(&b, 1e-9));
+        assert!(a.is_parallel(&b, 1e-4));
+    }
+
+    #[test]
+    fn test_distance_to_point() {
+        let horizontal = Line::new(Point::new(0.0, 1.0), Point::new(4.0, 1.0));
+        
+        assert_eq!(horizontal.distance_to_point(&Point::new(2.0, 4.0)), 3.0);
+        assert_eq!(horizontal.distance_to_point(&Point::new(2.0, -1.0)), 2.0);
+        // Infinite line: beyond the end still measures the perpendicular gap
+        assert_eq!(horizontal.distance_to_point(&Point::new(10.0, 4.0)), 3.0);
+        assert_eq!(horizontal.closest_point(&Point::new(10.0, 4.0)), Point::new(10.0, 1.0));
+        
+        let diagonal = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        assert_eq!(diagonal.closest_point(&Point::new(0.0, 2.0)), Point::new(1.0, 1.0));
+        assert!((diagonal.distance_to_point(&Point::new(0.0, 2.0)) - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_distance_to_point_zero_length() {
+        let degenerate = Line::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0));
+        
+        assert_eq!(degenerate.distance_to_point(&Point::new(4.0, 5.0)), 5.0);
+        assert_eq!(degenerate.closest_point(&Point::new(4.0, 5.0)), Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_point_at() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(3.0, 5.0));
+        
+        assert_eq!(line.point_at(0.0), *line.start());
+        assert_eq!(line.point_at(1.0), *line.end());
+        assert_eq!(line.point_at(0.5), line.midpoint());
+        assert_eq!(line.point_at(2.0), Point::new(5.0, 9.0));
+        assert_eq!(line.point_at(-0.5), Point::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn test_direction_and_reversed() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        let reversed = line.reversed();
+        
+        assert_eq!(*reversed.start(), Point::new(1.0, 1.0));
+        assert_eq!(*reversed.end(), Point::new(0.0, 0.0));
+        assert_eq!(reversed.reversed(), line);
+        assert!((reversed.angle() - (-3.0 * std::f64::consts::PI / 4.0)).abs() < 1e-10);
+        
+        let unit = line.direction().unwrap();
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(unit.approx_eq(&Point::new(half_sqrt2, half_sqrt2), 1e-12));
+        assert_eq!(Line::horizontal(5.0).direction(), Some(Point::new(1.0, 0.0)));
+        assert_eq!(Line::default().direction(), None);
+    }
+
+    #[test]
+    fn test_intersects_circle() {
+        let circle = Circle::new(Point::new(1.0, 1.0), 2.0);
+        
+        // Diameter extended past both sides: two points in start-to-end order
+        let across = Line::new(Point::new(-3.0, 1.0), Point::new(5.0, 1.0));
+        assert_eq!(across.intersects_circle(&circle), vec![Point::new(-1.0, 1.0), Point::new(3.0, 1.0)]);
+        assert_eq!(across.reversed().intersects_circle(&circle), vec![Point::new(3.0, 1.0), Point::new(-1.0, 1.0)]);
+        
+        // Tangent along the top of the circle
+        let tangent = Line::new(Point::new(-2.0, 3.0), Point::new(4.0, 3.0));
+        assert_eq!(tangent.intersects_circle(&circle), vec![Point::new(1.0, 3.0)]);
+        
+        // Starts inside: only the exit point
+        let from_center = Line::new(Point::new(1.0, 1.0), Point::new(1.0, 10.0));
+        assert_eq!(from_center.intersects_circle(&circle), vec![Point::new(1.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_intersects_circle_misses() {
+        let circle = Circle::new(Point::new(0.0, 0.0), 1.0);
+        
+        // The infinite extension would cross, the segment stops short
+        let short = Line::new(Point::new(2.0, 0.0), Point::new(5.0, 0.0));
+        assert!(short.intersects_circle(&circle).is_empty());
+        
+        // Entirely inside
+        let inside = Line::new(Point::new(-0.5, 0.0), Point::new(0.5, 0.0));
+        assert!(inside.intersects_circle(&circle).is_empty());
+        
+        let far = Line::new(Point::new(-5.0, 3.0), Point::new(5.0, 3.0));
+        assert!(far.intersects_circle(&circle).is_empty());
+        
+        let on_boundary = Line::new(Point::new(0.0, 1.0), Point::new(0.0, 1.0));
+        assert_eq!(on_boundary.intersects_circle(&circle), vec![Point::new(0.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = Line::new(Point::new(0.0, 1.0), Point::new(3.25, -4.0)).to_svg();
+        assert!(svg.starts_with("<line "));
+        assert!(svg.contains(r#"x1="0.00" y1="1.00""#));
+        assert!(svg.contains(r#"x2="3.25" y2="-4.00""#));
+        assert!(svg.ends_with("/>"));
+    }
+
+    #[test]
+    fn test_equality() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 2.0));
+        assert_eq!(line, Line::new(Point::new(0.0, 0.0), Point::new(1.0, 2.0)));
+        assert_ne!(line, line.reversed());
+
+        let nudged = Line::new(Point::new(1e-10, 0.0), Point::new(1.0, 2.0 - 1e-10));
+        assert_ne!(line, nudged);
+        assert!(line.approx_eq(&nudged, 1e-9));
+        assert!(!line.approx_eq(&nudged, 1e-11));
+        assert!(!line.approx_eq(&line.reversed(), 1e-9));
+    }
+
+    #[test]
+    fn test_perpendicular_bisector() {
+        let horizontal = Line::new(Point::new(-1.0, 2.0), Point::new(3.0, 2.0));
+        let bisector = horizontal.perpendicular_bisector().unwrap();
+
+        assert!(bisector.is_vertical());
+        assert_eq!(bisector.midpoint(), horizontal.midpoint());
+        assert_eq!(bisector.length(), horizontal.length());
+        assert_eq!(bisector, Line::new(Point::new(1.0, 0.0), Point::new(1.0, 4.0)));
+
+        // Every point on the bisector is equidistant from the endpoints
+        let diagonal = Line::new(Point::new(0.5, -2.0), Point::new(4.0, 1.0));
+        let bisector = diagonal.perpendicular_bisector().unwrap();
+        assert!(bisector.is_perpendicular(&diagonal, 1e-12));
+        let p = bisector.point_at(0.8);
+        assert!((p.distance(diagonal.start()) - p.distance(diagonal.end())).abs() < 1e-12);
+
+        let p = Point::new(2.0, 2.0);
+        assert_eq!(Line::new(p, p).perpendicular_bisector(), None);
+    }
+
+    #[test]
+    fn test_length_squared() {
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(4.0, 6.0));
+        assert_eq!(line.length_squared(), 25.0);
+        assert_eq!(line.length(), 5.0);
+
+        let skew = Line::new(Point::new(-0.3, 1.7), Point::new(2.9, -4.1));
+        assert!((skew.length_squared() - skew.length().powi(2)).abs() < 1e-12);
+        assert_eq!(Line::new(Point::ORIGIN, Point::ORIGIN).length_squared(), 0.0);
+    }
+
+    #[test]
+    fn test_area_and_perimeter() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(4.0, 5.0));
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(line.perimeter(), line.length());
+        assert_eq!(line.perimeter(), 5.0);
+
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(line),
+            Box::new(Circle::new(Point::new(0.0, 0.0), 1.0)),
+        ];
+        let total_area: f64 = shapes.iter().map(|s| s.area()).sum();
+        assert_eq!(total_area, std::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_from_slope_intercept() {
+        let line = Line::from_slope_intercept(2.0, -3.0, -1.0, 4.0);
+        for p in [line.start(), line.end()] {
+            assert!((p.y() - (2.0 * p.x() - 3.0)).abs() < 1e-12);
+        }
+        assert_eq!(line.start().x(), -1.0);
+        assert_eq!(line.end().x(), 4.0);
+
+        assert!((line.slope().unwrap() - 2.0).abs() < 1e-12);
+        assert!((line.y_intercept().unwrap() + 3.0).abs() < 1e-12);
+
+        let flat = Line::from_slope_intercept(0.0, 1.5, 0.0, 10.0);
+        assert!(flat.is_horizontal());
+        assert_eq!(flat.y_intercept(), Some(1.5));
+    }
+
+    #[test]
+    fn test_contains_point_on_segment() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+
+        assert!(line.contains_point_on_segment(&Point::new(2.0, 1.0), 1e-9));
+        assert!(line.contains_point_on_segment(line.start(), 1e-9));
+        assert!(line.contains_point_on_segment(line.end(), 0.0));
+
+        // Collinear but past the end, and just off to the side
+        assert!(!line.contains_point_on_segment(&Point::new(6.0, 3.0), 1e-9));
+        assert!(!line.contains_point_on_segment(&Point::new(-2.0, -1.0), 1e-9));
+        assert!(!line.contains_point_on_segment(&Point::new(2.0, 1.1), 0.05));
+        assert!(line.contains_point_on_segment(&Point::new(2.0, 1.1), 0.1));
+
+        let p = Point::new(1.0, 1.0);
+        assert!(Line::new(p, p).contains_point_on_segment(&p, 0.0));
+    }
 }
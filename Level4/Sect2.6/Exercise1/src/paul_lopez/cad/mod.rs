@@ -25,16 +25,35 @@
 
 // Declare individual class modules
 mod point;
+mod point3d;
+mod vector;
 mod line;
 mod circle;
 mod shape;
+mod polygon;
+mod rectangle;
+mod triangle;
+mod bounds;
+mod json;
+mod transform;
+pub mod algorithms;
+pub mod tolerance;
+pub mod traits;
 
 // Re-export all classes to make them accessible from this module
 // This allows: use paul_lopez::cad::Point; instead of use paul_lopez::cad::point::Point;
-pub use point::Point;
+pub use point::{Point, PointFmt};
+pub use point3d::Point3D;
+pub use vector::Vector2D;
+pub use transform::Matrix2x2;
 pub use line::Line;
-pub use circle::Circle;
+pub use circle::{Circle, CircleRelation};
 pub use shape::Shape;
+pub use polygon::Polygon;
+pub use rectangle::Rectangle;
+pub use triangle::Triangle;
+pub use bounds::{AABB, BoundsBuilder};
+pub use traits::{Drawable, Measurable, Scalable, Translatable, total_extent};
 
 // CAD-specific utilities and constants
 pub const PI: f64 = std::f64::consts::PI;
@@ -61,7 +80,7 @@ pub fn unit_square_points() -> [Point; 4] {
 
 /// CAD module information
 pub fn module_info() -> String {
-    format!("CAD module - Geometric classes for 2D computer-aided design")
+    "CAD module - Geometric classes for 2D computer-aided design".to_string()
 }
 
 #[cfg(test)]
@@ -1,8 +1,17 @@
 // Point class in CAD namespace - paul_lopez::cad::Point
 // =====================================================
 
+use super::json::JsonObject;
+use super::Vector2D;
+use crate::paul_lopez::error::GeometryError;
+use super::tolerance;
+use super::traits::{Drawable, Scalable, Translatable};
+use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Neg, Mul, Add, MulAssign};
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{Neg, Mul, Div, Add, Sub, AddAssign, MulAssign, DivAssign, SubAssign};
+use std::ops::{Index, IndexMut};
 
 /// 2D Point class
 /// 
@@ -15,6 +24,9 @@ pub struct Point {
 }
 
 impl Point {
+    /// The mathematical origin (0, 0)
+    pub const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+    
     /// Create a new point with given coordinates
     pub fn new(x: f64, y: f64) -> Self {
         Point { x, y }
@@ -30,6 +42,11 @@ impl Point {
         Point::new(value, value)
     }
     
+    /// Create a point from polar coordinates (angle in radians)
+    pub fn from_polar(radius: f64, theta: f64) -> Self {
+        Point::new(radius * theta.cos(), radius * theta.sin())
+    }
+    
     /// Get the x coordinate
     pub fn x(&self) -> f64 {
         self.x
@@ -50,16 +67,42 @@ impl Point {
         self.y = y;
     }
     
+    /// Iterate over the coordinates: x, then y (same order as p[0], p[1])
+    pub fn coords(&self) -> impl Iterator<Item = f64> {
+        [self.x, self.y].into_iter()
+    }
+    
     /// Calculate distance to another point
     pub fn distance(&self, other: &Point) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+    
+    /// Calculate squared distance to another point
+    /// Cheaper than distance when only comparing distances
+    pub fn distance_squared(&self, other: &Point) -> f64 {
         let dx = self.x - other.x;
         let dy = self.y - other.y;
-        (dx * dx + dy * dy).sqrt()
+        dx * dx + dy * dy
+    }
+    
+    /// Calculate the Manhattan (L1) distance: |dx| + |dy|
+    pub fn manhattan_distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+    
+    /// Calculate the Chebyshev (L∞) distance: max(|dx|, |dy|)
+    pub fn chebyshev_distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
     }
     
     /// Calculate distance to origin
     pub fn distance_to_origin(&self) -> f64 {
-        (self.x * self.x + self.y * self.y).sqrt()
+        self.distance_to_origin_squared().sqrt()
+    }
+    
+    /// Calculate squared distance to origin
+    pub fn distance_to_origin_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y
     }
     
     /// Get the magnitude (distance from origin)
@@ -67,11 +110,62 @@ impl Point {
         self.distance_to_origin()
     }
     
+    /// Get the unit vector in the same direction
+    /// Returns None for the zero vector instead of producing NaN
+    pub fn normalized(&self) -> Option<Point> {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            None
+        } else {
+            Some(Point::new(self.x / magnitude, self.y / magnitude))
+        }
+    }
+    
+    /// Dot product, treating both points as vectors from the origin
+    pub fn dot(&self, other: &Point) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+    
+    /// 2D cross product (z component of the 3D cross product),
+    /// treating both points as vectors from the origin
+    ///
+    /// Positive when `other` is counterclockwise from `self`, negative when
+    /// clockwise and zero when the two are parallel.
+    pub fn cross(&self, other: &Point) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+    
+    /// Component-wise minimum of two points
+    pub fn min(&self, other: &Point) -> Point {
+        Point::new(self.x.min(other.x), self.y.min(other.y))
+    }
+    
+    /// Component-wise maximum of two points
+    pub fn max(&self, other: &Point) -> Point {
+        Point::new(self.x.max(other.x), self.y.max(other.y))
+    }
+    
+    /// Component-wise absolute value
+    pub fn abs(&self) -> Point {
+        Point::new(self.x.abs(), self.y.abs())
+    }
+    
+    /// Clamp each coordinate into the box spanned by `lo` and `hi`
+    /// (`lo` must not exceed `hi` in either coordinate)
+    pub fn clamp(&self, lo: &Point, hi: &Point) -> Point {
+        self.max(lo).min(hi)
+    }
+    
     /// Translate point by given offset
     pub fn translate(&self, dx: f64, dy: f64) -> Point {
         Point::new(self.x + dx, self.y + dy)
     }
     
+    /// Translate point by a displacement vector (same as `*self + *v`)
+    pub fn translate_by(&self, v: &Vector2D) -> Point {
+        self.translate(v.dx(), v.dy())
+    }
+    
     /// Rotate point around origin by given angle (in radians)
     pub fn rotate(&self, angle: f64) -> Point {
         let cos_a = angle.cos();
@@ -81,6 +175,144 @@ impl Point {
             self.x * sin_a + self.y * cos_a
         )
     }
+    
+    /// Rotate point around a pivot by given angle (in radians)
+    pub fn rotate_around(&self, pivot: &Point, angle: f64) -> Point {
+        (*self - *pivot).rotate(angle) + *pivot
+    }
+    
+    /// Scale the offset from a pivot by `factor` (zoom about the pivot)
+    ///
+    /// A factor of 1 returns the point exactly; going through the offset
+    /// could otherwise change the last bits through rounding.
+    pub fn scale_about(&self, pivot: &Point, factor: f64) -> Point {
+        if factor == 1.0 {
+            return *self;
+        }
+        (*self - *pivot) * factor + *pivot
+    }
+    
+    /// Mirror across the x axis: (x, y) -> (x, -y)
+    pub fn reflect_x(&self) -> Point {
+        Point::new(self.x, -self.y)
+    }
+    
+    /// Mirror across the y axis: (x, y) -> (-x, y)
+    pub fn reflect_y(&self) -> Point {
+        Point::new(-self.x, self.y)
+    }
+    
+    /// Mirror across the line through the origin in the direction of `axis`
+    ///
+    /// Only the direction of `axis` matters, not its length. A zero axis
+    /// defines no line, so the point is returned unchanged.
+    pub fn reflect_across(&self, axis: &Point) -> Point {
+        let axis_squared = axis.distance_to_origin_squared();
+        if axis_squared == 0.0 {
+            return *self;
+        }
+        
+        // Twice the projection onto the axis, minus the point itself
+        let projection = *axis * (self.dot(axis) / axis_squared);
+        projection * 2.0 - *self
+    }
+    
+    /// Get the point halfway between this point and another
+    pub fn midpoint(&self, other: &Point) -> Point {
+        self.lerp(other, 0.5)
+    }
+    
+    /// Linear interpolation: t = 0 gives self, t = 1 gives other
+    /// Values outside [0, 1] extrapolate along the same line
+    pub fn lerp(&self, other: &Point, t: f64) -> Point {
+        Point::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t
+        )
+    }
+    
+    /// Move up to `distance` units toward `target`
+    ///
+    /// Never overshoots: if the target is closer than `distance` the
+    /// result is the target itself. A negative distance moves away from the
+    /// target; with no direction to move in (the points coincide) the
+    /// result is the starting point.
+    pub fn towards(&self, target: &Point, distance: f64) -> Point {
+        let separation = self.distance(target);
+        if separation == 0.0 {
+            return *self;
+        }
+        if distance >= separation {
+            return *target;
+        }
+        
+        self.lerp(target, distance / separation)
+    }
+    
+    /// Convert to polar coordinates: (radius, theta) with theta in (-π, π]
+    /// The origin gives (0, 0)
+    pub fn to_polar(self) -> (f64, f64) {
+        let radius = self.distance_to_origin();
+        if radius == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (radius, self.y.atan2(self.x))
+        }
+    }
+    
+    /// Format the point for a locale
+    ///
+    /// With `use_comma` the decimal separator becomes ',' and the
+    /// coordinates are separated by ';' so the output stays unambiguous:
+    /// `Point(1,50; 3,90)` instead of `Point(1.50, 3.90)`
+    pub fn to_string_locale(self, use_comma: bool, decimals: usize) -> String {
+        let x = format!("{:.*}", decimals, self.x);
+        let y = format!("{:.*}", decimals, self.y);
+        
+        if use_comma {
+            format!("Point({}; {})", x.replace('.', ","), y.replace('.', ","))
+        } else {
+            format!("Point({}, {})", x, y)
+        }
+    }
+    
+    /// Serialize as a JSON object: `{"x":1.0,"y":2.0}`
+    pub fn to_json(self) -> String {
+        format!("{{\"x\":{:?},\"y\":{:?}}}", self.x, self.y)
+    }
+    
+    /// Parse a point from the JSON written by to_json
+    pub fn from_json(json: &str) -> Result<Point, GeometryError> {
+        let object = JsonObject::parse(json)?;
+        Ok(Point::new(object.number("x")?, object.number("y")?))
+    }
+    
+    /// Render as an SVG marker: a small circle centred on the point
+    pub fn to_svg(self) -> String {
+        format!("<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"2\"/>", self.x, self.y)
+    }
+    
+    /// Serialize as a GeoJSON Point geometry:
+    /// `{"type":"Point","coordinates":[1.0,2.0]}`
+    pub fn to_geojson(self) -> String {
+        format!("{{\"type\":\"Point\",\"coordinates\":[{:?},{:?}]}}", self.x, self.y)
+    }
+    
+    /// Wrap for display with `precision` decimals instead of the default 2
+    pub fn with_precision(&self, precision: usize) -> PointFmt {
+        PointFmt { point: *self, precision }
+    }
+    
+    /// Check if both coordinates differ by at most `epsilon`
+    pub fn approx_eq(&self, other: &Point, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+    
+    /// Check if both coordinates are within the global default epsilon
+    /// (see paul_lopez::cad::tolerance)
+    pub fn approx_eq_default(&self, other: &Point) -> bool {
+        self.approx_eq(other, tolerance::default_epsilon())
+    }
 }
 
 // Default implementation
@@ -93,7 +325,25 @@ impl Default for Point {
 // Display implementation for pretty printing
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Point({:.2}, {:.2})", self.x, self.y)
+        // Two decimals unless the caller asks otherwise, e.g. {:.4}
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "Point({:.*}, {:.*})", precision, self.x, precision, self.y)
+    }
+}
+
+/// Display wrapper printing a Point with a fixed number of decimals
+///
+/// Created by `Point::with_precision`; handy where a format string can't
+/// carry the precision, e.g. when the value is passed on to other code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointFmt {
+    point: Point,
+    precision: usize,
+}
+
+impl fmt::Display for PointFmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", self.precision, self.point)
     }
 }
 
@@ -117,6 +367,18 @@ impl Mul<f64> for Point {
     }
 }
 
+/// Scalar division: point / divisor
+///
+/// Follows f64 semantics, so dividing by zero gives ±inf (or NaN for a
+/// zero coordinate) instead of panicking.
+impl Div<f64> for Point {
+    type Output = Point;
+    
+    fn div(self, divisor: f64) -> Self::Output {
+        Point::new(self.x / divisor, self.y / divisor)
+    }
+}
+
 /// Point addition: point1 + point2
 impl Add for Point {
     type Output = Point;
@@ -126,6 +388,31 @@ impl Add for Point {
     }
 }
 
+/// Compound assignment: point1 += point2
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+/// Point subtraction: point1 - point2 (displacement from point2 to point1)
+impl Sub for Point {
+    type Output = Point;
+    
+    fn sub(self, other: Point) -> Self::Output {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Compound assignment: point1 -= point2
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
 /// Compound assignment: point *= factor
 impl MulAssign<f64> for Point {
     fn mul_assign(&mut self, factor: f64) {
@@ -134,6 +421,14 @@ impl MulAssign<f64> for Point {
     }
 }
 
+/// Compound assignment: point /= divisor (same zero-division rules as Div)
+impl DivAssign<f64> for Point {
+    fn div_assign(&mut self, divisor: f64) {
+        self.x /= divisor;
+        self.y /= divisor;
+    }
+}
+
 /// Allow f64 * Point (commutative multiplication)
 impl Mul<Point> for f64 {
     type Output = Point;
@@ -143,6 +438,96 @@ impl Mul<Point> for f64 {
     }
 }
 
+/// Total of a sequence of points: points.into_iter().sum::<Point>()
+/// An empty sequence sums to the origin
+impl Sum for Point {
+    fn sum<I: Iterator<Item = Point>>(iter: I) -> Self {
+        iter.fold(Point::ORIGIN, |acc, p| acc + p)
+    }
+}
+
+/// Total of a sequence of borrowed points: points.iter().sum::<Point>()
+impl<'a> Sum<&'a Point> for Point {
+    fn sum<I: Iterator<Item = &'a Point>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Hash on the raw bit patterns of the coordinates
+///
+/// Bit-pattern semantics differ from `==` at the edges: 0.0 and -0.0
+/// compare equal but hash differently, and a NaN coordinate hashes
+/// consistently even though it never compares equal to itself. Fine for
+/// deduplicating coordinates that came from the same computation.
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.to_bits().hash(state);
+        self.y.to_bits().hash(state);
+    }
+}
+
+/// Needed for HashMap/HashSet keys; like Hash, assumes coordinates are
+/// never NaN (NaN != NaN breaks the reflexivity Eq promises)
+impl Eq for Point {}
+
+/// Order by distance from the origin, nearest first
+///
+/// Points at the same distance are ordered by x, then y, so only points
+/// that compare equal with `==` give `Equal`. Any NaN coordinate gives
+/// None. Allows `points.sort_by(|a, b| a.partial_cmp(b).unwrap())`.
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        let by_distance = self.distance_to_origin_squared()
+            .partial_cmp(&other.distance_to_origin_squared())?;
+        Some(by_distance
+            .then(self.x.partial_cmp(&other.x)?)
+            .then(self.y.partial_cmp(&other.y)?))
+    }
+}
+
+/// Coordinate access by axis: p[0] is x, p[1] is y
+/// Panics for any other index
+impl Index<usize> for Point {
+    type Output = f64;
+    
+    fn index(&self, axis: usize) -> &f64 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Point axis index out of range: {} (expected 0 for x or 1 for y)", axis),
+        }
+    }
+}
+
+impl IndexMut<usize> for Point {
+    fn index_mut(&mut self, axis: usize) -> &mut f64 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Point axis index out of range: {} (expected 0 for x or 1 for y)", axis),
+        }
+    }
+}
+
+/// A point is drawn as a single marker at its nearest cell
+impl Drawable for Point {
+    fn raster_cells(&self) -> Vec<(i64, i64)> {
+        vec![(self.x.round() as i64, self.y.round() as i64)]
+    }
+}
+
+impl Scalable for Point {
+    fn scale_about(&self, pivot: &Point, factor: f64) -> Point {
+        Point::scale_about(self, pivot, factor)
+    }
+}
+
+impl Translatable for Point {
+    fn translate(&self, dx: f64, dy: f64) -> Point {
+        Point::translate(self, dx, dy)
+    }
+}
+
 // Conversion traits
 impl From<f64> for Point {
     fn from(value: f64) -> Self {
@@ -156,6 +541,18 @@ impl From<(f64, f64)> for Point {
     }
 }
 
+impl From<[f64; 2]> for Point {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (f64, f64) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
 // Cross-type comparisons
 impl PartialEq<f64> for Point {
     fn eq(&self, other: &f64) -> bool {
@@ -215,6 +612,16 @@ mod tests {
         assert_eq!(negated, Point::new(-1.0, -2.0));
     }
 
+    #[test]
+    fn test_to_string_locale() {
+        let p = Point::new(1.5, 3.9);
+        
+        assert_eq!(p.to_string_locale(false, 2), "Point(1.50, 3.90)");
+        assert_eq!(p.to_string_locale(true, 2), "Point(1,50; 3,90)");
+        assert_eq!(p.to_string_locale(true, 0), "Point(2; 4)");
+        assert_eq!(Point::new(-0.25, 10.0).to_string_locale(true, 3), "Point(-0,250; 10,000)");
+    }
+
     #[test]
     fn test_conversions() {
         let p1: Point = 5.0.into();
@@ -222,5 +629,394 @@ mod tests {
         
         let p2: Point = (3.0, 4.0).into();
         assert_eq!(p2, Point::new(3.0, 4.0));
+        
+        let p3: Point = [-1.5, 2.0].into();
+        assert_eq!(p3, Point::new(-1.5, 2.0));
+        
+        // Round trip through a tuple in both directions
+        let pair: (f64, f64) = p2.into();
+        assert_eq!(pair, (3.0, 4.0));
+        assert_eq!(Point::from(pair), p2);
+        assert_eq!(<(f64, f64)>::from(Point::from((7.0, -8.0))), (7.0, -8.0));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let p = Point::new(1.5, -0.1);
+        
+        assert_eq!(p.to_json(), r#"{"x":1.5,"y":-0.1}"#);
+        assert_eq!(Point::from_json(&p.to_json()), Ok(p));
+        assert_eq!(Point::from_json(r#" { "y": 2, "x": 3e2 } "#), Ok(Point::new(300.0, 2.0)));
+    }
+
+    #[test]
+    fn test_json_malformed() {
+        assert_eq!(Point::from_json(r#"{"x":1.0}"#), Err(GeometryError::ParseError("missing field \"y\"".to_string())));
+        assert!(Point::from_json(r#"{"x":1.0,"y":}"#).is_err());
+        assert!(Point::from_json("Point(1.0, 2.0)").is_err());
+    }
+
+    #[test]
+    fn test_towards() {
+        let start = Point::new(0.0, 0.0);
+        let target = Point::new(6.0, 8.0);
+        
+        assert_eq!(start.towards(&target, 5.0), Point::new(3.0, 4.0));
+        assert_eq!(start.towards(&target, 10.0), target);
+        assert_eq!(start.towards(&target, 25.0), target);
+        assert_eq!(start.towards(&target, 0.0), start);
+        
+        // Already there: stays put rather than dividing by zero
+        assert_eq!(target.towards(&target, 1.0), target);
+        assert_eq!(target.towards(&target, -1.0), target);
+        assert_eq!(target.towards(&target, 0.0), target);
+        
+        // Negative distance backs away
+        assert_eq!(start.towards(&target, -5.0), Point::new(-3.0, -4.0));
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let p1 = Point::new(1.0, -2.0);
+        let p2 = Point::new(-3.0, -4.5);
+        
+        assert_eq!(p1 - p2, Point::new(4.0, 2.5));
+        assert_eq!(p2 - p1, Point::new(-4.0, -2.5));
+        assert_eq!(p1 - p1, Point::default());
+        assert_eq!(p1 - p2, p1 + (-p2));
+        
+        let mut p3 = p1;
+        p3 -= p2;
+        assert_eq!(p3, Point::new(4.0, 2.5));
+        p3 -= p3;
+        assert_eq!(p3, Point::default());
+    }
+
+    #[test]
+    fn test_normalized() {
+        let unit = Point::new(3.0, 4.0).normalized().unwrap();
+        assert!((unit.x() - 0.6).abs() < 1e-12);
+        assert!((unit.y() - 0.8).abs() < 1e-12);
+        assert!((unit.magnitude() - 1.0).abs() < 1e-12);
+        
+        assert_eq!(Point::new(0.0, -2.0).normalized(), Some(Point::new(0.0, -1.0)));
+        assert_eq!(Point::default().normalized(), None);
+    }
+
+    #[test]
+    fn test_midpoint_and_lerp() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 6.0);
+        
+        assert_eq!(a.midpoint(&b), Point::new(2.0, 3.0));
+        assert_eq!(a.lerp(&b, 0.5), a.midpoint(&b));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.25), Point::new(1.0, 1.5));
+    }
+
+    #[test]
+    fn test_rotate_around() {
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        
+        let rotated = Point::new(1.0, 0.0).rotate(half_pi);
+        assert!((rotated.x() - 0.0).abs() < 1e-10);
+        assert!((rotated.y() - 1.0).abs() < 1e-10);
+        
+        let pivot = Point::new(2.0, 1.0);
+        let around = Point::new(3.0, 1.0).rotate_around(&pivot, half_pi);
+        assert!((around.x() - 2.0).abs() < 1e-10);
+        assert!((around.y() - 2.0).abs() < 1e-10);
+        
+        // The pivot itself never moves
+        assert_eq!(pivot.rotate_around(&pivot, 1.234), pivot);
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        for &(radius, theta) in &[(1.0, 0.0), (2.5, 1.0), (4.0, -2.0), (0.5, std::f64::consts::PI)] {
+            let (r, t) = Point::from_polar(radius, theta).to_polar();
+            assert!((r - radius).abs() < 1e-12);
+            assert!((t - theta).abs() < 1e-12);
+        }
+        
+        let p = Point::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+        assert!((p.x() - 0.0).abs() < 1e-12);
+        assert!((p.y() - 2.0).abs() < 1e-12);
+        
+        assert_eq!(Point::default().to_polar(), (0.0, 0.0));
+        assert_eq!(Point::new(-0.0, 0.0).to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(1.0 + 1e-10, 2.0 - 1e-10);
+        
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-12));
+        assert!(a.approx_eq(&a, 0.0));
+        
+        // Both components must be close, not just one
+        assert!(!a.approx_eq(&Point::new(1.0, 2.1), 1e-6));
+    }
+
+    #[test]
+    fn test_distance_squared() {
+        let p1 = Point::new(1.0, -2.0);
+        let p2 = Point::new(4.5, 3.0);
+        
+        assert!((p1.distance_squared(&p2) - p1.distance(&p2).powi(2)).abs() < 1e-10);
+        assert_eq!(Point::new(3.0, 4.0).distance_to_origin_squared(), 25.0);
+        assert_eq!(p1.distance_squared(&p1), 0.0);
+    }
+
+    #[test]
+    fn test_distance_squared_nearest_search() {
+        // Finding the nearest of many points gives the same answer without sqrt
+        let target = Point::new(0.3, -0.7);
+        let points: Vec<Point> = (0..10_000)
+            .map(|i| Point::from_polar(1.0 + (i % 97) as f64, i as f64 * 0.618))
+            .collect();
+        
+        let by_distance = points.iter()
+            .enumerate()
+            .min_by(|a, b| a.1.distance(&target).total_cmp(&b.1.distance(&target)))
+            .map(|(i, _)| i);
+        let by_squared = points.iter()
+            .enumerate()
+            .min_by(|a, b| a.1.distance_squared(&target).total_cmp(&b.1.distance_squared(&target)))
+            .map(|(i, _)| i);
+        
+        assert_eq!(by_distance, by_squared);
+    }
+
+    #[test]
+    fn test_manhattan_and_chebyshev() {
+        let origin = Point::new(0.0, 0.0);
+        let p = Point::new(3.0, 4.0);
+        
+        assert_eq!(origin.manhattan_distance(&p), 7.0);
+        assert_eq!(origin.chebyshev_distance(&p), 4.0);
+        assert_eq!(p.manhattan_distance(&origin), 7.0);
+        assert_eq!(Point::new(-1.0, 2.0).chebyshev_distance(&Point::new(4.0, 0.0)), 5.0);
+        
+        // Chebyshev <= Euclidean <= Manhattan
+        assert!(origin.chebyshev_distance(&p) <= origin.distance(&p));
+        assert!(origin.distance(&p) <= origin.manhattan_distance(&p));
+    }
+
+    #[test]
+    fn test_sum() {
+        let points = [Point::new(1.0, 2.0), Point::new(3.0, -4.0), Point::new(5.0, 8.0)];
+        let manual = Point::new(1.0 + 3.0 + 5.0, 2.0 - 4.0 + 8.0);
+        
+        assert_eq!(points.iter().sum::<Point>(), manual);
+        assert_eq!(points.iter().copied().sum::<Point>(), manual);
+        assert_eq!(Vec::<Point>::new().into_iter().sum::<Point>(), Point::default());
+        
+        let centroid = points.iter().sum::<Point>() * (1.0 / points.len() as f64);
+        assert_eq!(centroid, Point::new(3.0, 2.0));
+    }
+
+    #[test]
+    fn test_hash_set_deduplicates() {
+        use std::collections::HashSet;
+        
+        let mut set = HashSet::new();
+        set.insert(Point::new(1.5, -2.0));
+        set.insert(Point::new(1.5, -2.0));
+        assert_eq!(set.len(), 1);
+        
+        set.insert(Point::new(-2.0, 1.5));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Point::new(1.5, -2.0)));
+    }
+
+    #[test]
+    fn test_component_wise_min_max_abs_clamp() {
+        let a = Point::new(1.0, 5.0);
+        let b = Point::new(3.0, 2.0);
+        
+        assert_eq!(a.min(&b), Point::new(1.0, 2.0));
+        assert_eq!(a.max(&b), Point::new(3.0, 5.0));
+        assert_eq!(Point::new(-1.5, 2.0).abs(), Point::new(1.5, 2.0));
+        
+        let lo = Point::new(0.0, 0.0);
+        let hi = Point::new(10.0, 10.0);
+        assert_eq!(Point::new(-3.0, 4.0).clamp(&lo, &hi), Point::new(0.0, 4.0));
+        assert_eq!(Point::new(12.0, 11.0).clamp(&lo, &hi), hi);
+        assert_eq!(Point::new(5.0, 5.0).clamp(&lo, &hi), Point::new(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_scalar_times_point() {
+        let p = Point::new(1.0, 2.0);
+        
+        assert_eq!(2.0 * p, Point::new(2.0, 4.0));
+        assert_eq!(2.0 * p, p * 2.0);
+        assert_eq!(-0.5 * p, -(p * 0.5));
+    }
+
+    #[test]
+    fn test_division() {
+        assert_eq!(Point::new(4.0, 6.0) / 2.0, Point::new(2.0, 3.0));
+        
+        let mut p = Point::new(4.0, -6.0);
+        p /= 2.0;
+        assert_eq!(p, Point::new(2.0, -3.0));
+        
+        let inf = Point::new(1.0, -1.0) / 0.0;
+        assert_eq!(inf, Point::new(f64::INFINITY, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_add_assign_and_origin() {
+        assert_eq!(Point::ORIGIN, Point::default());
+        
+        let mut total = Point::ORIGIN;
+        for i in 1..=3 {
+            total += Point::new(i as f64, -(i as f64));
+        }
+        assert_eq!(total, Point::new(6.0, -6.0));
+    }
+
+    #[test]
+    fn test_cross() {
+        let x_axis = Point::new(1.0, 0.0);
+        let y_axis = Point::new(0.0, 1.0);
+        
+        assert_eq!(x_axis.cross(&y_axis), 1.0);
+        assert_eq!(y_axis.cross(&x_axis), -1.0);
+        assert_eq!(Point::new(2.0, 4.0).cross(&Point::new(-1.0, -2.0)), 0.0);
+        assert_eq!(Point::new(3.0, 1.0).cross(&Point::new(1.0, 2.0)), 5.0);
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Point::new(1.0, 2.0).dot(&Point::new(3.0, 4.0)), 11.0);
+        assert_eq!(Point::new(1.0, 0.0).dot(&Point::new(0.0, 5.0)), 0.0);
+        assert_eq!(Point::new(3.0, 4.0).dot(&Point::new(3.0, 4.0)), 25.0);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let p = Point::new(1.23456, -2.0);
+        
+        assert_eq!(p.to_string(), "Point(1.23, -2.00)");
+        assert_eq!(format!("{:.4}", p), "Point(1.2346, -2.0000)");
+        assert_eq!(format!("{:.0}", p), "Point(1, -2)");
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = Point::new(1.5, -2.0).to_svg();
+        assert_eq!(svg, r#"<circle cx="1.50" cy="-2.00" r="2"/>"#);
+    }
+
+    #[test]
+    fn test_to_geojson() {
+        assert_eq!(Point::new(1.0, -2.5).to_geojson(), r#"{"type":"Point","coordinates":[1.0,-2.5]}"#);
+    }
+
+    #[test]
+    fn test_partial_ord_by_distance() {
+        assert!(Point::new(1.0, 1.0) < Point::new(3.0, 3.0));
+        assert!(Point::new(-5.0, 0.0) > Point::new(0.0, 4.0));
+
+        let mut points = [Point::new(3.0, 3.0), Point::new(0.0, -2.0), Point::new(1.0, 1.0)];
+        points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(points, [Point::new(1.0, 1.0), Point::new(0.0, -2.0), Point::new(3.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_partial_ord_tiebreak_and_nan() {
+        // Same distance (5): ordered by x, then y
+        assert!(Point::new(-3.0, 4.0) < Point::new(3.0, 4.0));
+        assert!(Point::new(3.0, -4.0) < Point::new(3.0, 4.0));
+        assert_eq!(Point::new(3.0, 4.0).partial_cmp(&Point::new(3.0, 4.0)), Some(Ordering::Equal));
+
+        assert_eq!(Point::new(f64::NAN, 0.0).partial_cmp(&Point::ORIGIN), None);
+    }
+
+    #[test]
+    fn test_index_by_axis() {
+        let mut p = Point::new(3.0, -4.0);
+        assert_eq!(p[0], 3.0);
+        assert_eq!(p[1], -4.0);
+
+        p[0] = 1.5;
+        p[1] += 10.0;
+        assert_eq!(p, Point::new(1.5, 6.0));
+
+        let mut total = 0.0;
+        for axis in 0..2 {
+            total += p[axis];
+        }
+        assert_eq!(total, 7.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Point axis index out of range: 2")]
+    fn test_index_out_of_range() {
+        let _ = Point::new(1.0, 2.0)[2];
+    }
+
+    #[test]
+    fn test_coords_iterator() {
+        let p = Point::new(3.0, 4.0);
+        assert_eq!(p.coords().sum::<f64>(), 7.0);
+        assert_eq!(p.coords().collect::<Vec<_>>(), [3.0, 4.0]);
+        assert_eq!(p.coords().fold(f64::MIN, f64::max), 4.0);
+    }
+
+    #[test]
+    fn test_reflect_axes() {
+        assert_eq!(Point::new(1.0, 2.0).reflect_x(), Point::new(1.0, -2.0));
+        assert_eq!(Point::new(1.0, 2.0).reflect_y(), Point::new(-1.0, 2.0));
+
+        // Reflecting across the coordinate axes as arbitrary lines agrees
+        let p = Point::new(1.0, 2.0);
+        assert_eq!(p.reflect_across(&Point::new(5.0, 0.0)), p.reflect_x());
+        assert_eq!(p.reflect_across(&Point::new(0.0, -1.0)), p.reflect_y());
+    }
+
+    #[test]
+    fn test_reflect_across_diagonal() {
+        let p = Point::new(1.0, 3.0);
+        assert!(p.reflect_across(&Point::new(1.0, 1.0)).approx_eq(&Point::new(3.0, 1.0), 1e-12));
+        assert!(p.reflect_across(&Point::new(-2.0, -2.0)).approx_eq(&Point::new(3.0, 1.0), 1e-12));
+
+        // Zero axis: unchanged
+        assert_eq!(p.reflect_across(&Point::ORIGIN), p);
+    }
+
+    #[test]
+    fn test_translate_by_vector() {
+        let p = Point::new(1.0, 1.0);
+        assert_eq!(p.translate(2.0, 3.0), Point::new(3.0, 4.0));
+        assert_eq!(p.translate_by(&Vector2D::new(2.0, 3.0)), Point::new(3.0, 4.0));
+        assert_eq!(p.translate_by(&Vector2D::new(2.0, 3.0)), p + Vector2D::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_scale_about() {
+        let pivot = Point::new(1.0, 1.0);
+        assert_eq!(Point::new(2.0, 2.0).scale_about(&pivot, 2.0), Point::new(3.0, 3.0));
+        assert_eq!(Point::new(3.0, 5.0).scale_about(&pivot, 0.5), Point::new(2.0, 3.0));
+        assert_eq!(Point::new(3.0, 5.0).scale_about(&pivot, 0.0), pivot);
+
+        // Factor 1 is an exact no-op, even where the round trip would round
+        let p = Point::new(0.1, 0.7);
+        let pivot = Point::new(0.3, 1e16);
+        assert_eq!(p.scale_about(&pivot, 1.0), p);
+    }
+
+    #[test]
+    fn test_with_precision() {
+        let p = Point::new(1.5, 3.9);
+        assert_eq!(p.with_precision(5).to_string(), "Point(1.50000, 3.90000)");
+        assert_eq!(p.with_precision(0).to_string(), "Point(2, 4)");
+        assert_eq!(format!("{}", p.with_precision(2)), p.to_string());
     }
 }
@@ -0,0 +1,136 @@
+// Point3D class in CAD namespace - paul_lopez::cad::Point3D
+// =========================================================
+#![allow(dead_code)]
+
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+/// 3D Point class
+///
+/// This represents a point in 3D Cartesian coordinate system, e.g. a
+/// sample on a volatility surface. Independent of the 2D Point.
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point3D {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Point3D {
+    /// Create a new point with given coordinates
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Point3D { x, y, z }
+    }
+    
+    /// Create a point at the origin (0, 0, 0)
+    pub fn default() -> Self {
+        Point3D::new(0.0, 0.0, 0.0)
+    }
+    
+    /// Get the x coordinate
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+    
+    /// Get the y coordinate
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+    
+    /// Get the z coordinate
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+    
+    /// Calculate distance to another point
+    pub fn distance(&self, other: &Point3D) -> f64 {
+        (*self - *other).distance_to_origin()
+    }
+    
+    /// Calculate distance to origin
+    pub fn distance_to_origin(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    
+    /// Dot product, treating both points as vectors from the origin
+    pub fn dot(&self, other: &Point3D) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+// Default implementation
+impl Default for Point3D {
+    fn default() -> Self {
+        Point3D::new(0.0, 0.0, 0.0)
+    }
+}
+
+// Display implementation for pretty printing
+impl fmt::Display for Point3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Point3D({:.2}, {:.2}, {:.2})", self.x, self.y, self.z)
+    }
+}
+
+// Arithmetic operators
+
+/// Point addition: point1 + point2
+impl Add for Point3D {
+    type Output = Point3D;
+    
+    fn add(self, other: Point3D) -> Self::Output {
+        Point3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+/// Point subtraction: point1 - point2
+impl Sub for Point3D {
+    type Output = Point3D;
+    
+    fn sub(self, other: Point3D) -> Self::Output {
+        Point3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// Scalar multiplication: point * factor
+impl Mul<f64> for Point3D {
+    type Output = Point3D;
+    
+    fn mul(self, factor: f64) -> Self::Output {
+        Point3D::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point3d_creation() {
+        let p = Point3D::new(1.0, 2.0, 3.0);
+        assert_eq!((p.x(), p.y(), p.z()), (1.0, 2.0, 3.0));
+        assert_eq!(Point3D::default(), Point3D::new(0.0, 0.0, 0.0));
+        assert_eq!(p.to_string(), "Point3D(1.00, 2.00, 3.00)");
+    }
+
+    #[test]
+    fn test_point3d_distance() {
+        let p = Point3D::new(1.0, 2.0, 2.0);
+        
+        assert_eq!(p.distance_to_origin(), 3.0);
+        assert_eq!(p.distance(&Point3D::default()), 3.0);
+        assert_eq!(Point3D::new(2.0, 3.0, 4.0).distance(&Point3D::new(1.0, 1.0, 2.0)), 3.0);
+    }
+
+    #[test]
+    fn test_point3d_operators() {
+        let a = Point3D::new(1.0, 2.0, 3.0);
+        let b = Point3D::new(4.0, -5.0, 6.0);
+        
+        assert_eq!(a + b, Point3D::new(5.0, -3.0, 9.0));
+        assert_eq!(b - a, Point3D::new(3.0, -7.0, 3.0));
+        assert_eq!(a * 2.0, Point3D::new(2.0, 4.0, 6.0));
+        assert_eq!(a.dot(&b), 4.0 - 10.0 + 18.0);
+    }
+}
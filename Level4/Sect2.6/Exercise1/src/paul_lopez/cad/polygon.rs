@@ -0,0 +1,377 @@
+// Polygon class in CAD namespace - paul_lopez::cad::Polygon
+// ========================================================
+#![allow(dead_code)]
+
+use super::{Point, Triangle}; // Use classes from same CAD module
+use super::algorithms::cross;
+use super::traits::{Measurable, Shape};
+use crate::paul_lopez::containers::Array; // Cross-namespace usage
+use std::fmt;
+
+/// Polygon class defined by an ordered list of vertices
+///
+/// The closing edge from the last vertex back to the first is implied.
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Create a polygon from its vertices in order
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+    
+    /// Get the vertices
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+    
+    /// Get the number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+    
+    /// Calculate the perimeter, including the closing edge
+    /// Fewer than two vertices give zero
+    pub fn perimeter(&self) -> f64 {
+        let n = self.vertices.len();
+        if n < 2 {
+            return 0.0;
+        }
+        (0..n)
+            .map(|i| self.vertices[i].distance(&self.vertices[(i + 1) % n]))
+            .sum()
+    }
+    
+    /// Check if a point lies inside the polygon (ray casting)
+    ///
+    /// Casts a ray to the right and counts edge crossings; an odd count
+    /// means inside. Points exactly on an edge may go either way.
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        
+        let mut inside = false;
+        let mut j = n - 1;
+        for i in 0..n {
+            let (a, b) = (&self.vertices[i], &self.vertices[j]);
+            // Edge straddles the ray's horizontal line (half-open to count
+            // shared vertices once) and crosses it right of the point
+            if (a.y() > point.y()) != (b.y() > point.y()) {
+                let crossing_x = a.x() + (point.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y());
+                if point.x() < crossing_x {
+                    inside = !inside;
+                }
+            }
+            j = i;
+        }
+        inside
+    }
+    
+    /// Copy the vertices into an Array container, in order
+    pub fn to_array(&self) -> Array {
+        Array::from_vec(self.vertices.clone())
+    }
+    
+    /// Serialize as a GeoJSON Polygon geometry with a single exterior ring
+    ///
+    /// GeoJSON rings are explicitly closed, so the first vertex is repeated
+    /// at the end.
+    pub fn to_geojson(&self) -> String {
+        let ring: Vec<String> = self.vertices.iter()
+            .chain(self.vertices.first())
+            .map(|p| format!("[{:?},{:?}]", p.x(), p.y()))
+            .collect();
+        format!("{{\"type\":\"Polygon\",\"coordinates\":[[{}]]}}", ring.join(","))
+    }
+    
+    /// Signed area via the shoelace formula
+    /// Positive for counterclockwise vertex order, zero for fewer than 3 vertices
+    fn signed_area(&self) -> f64 {
+        if self.vertices.len() < 3 {
+            return 0.0;
+        }
+        
+        let n = self.vertices.len();
+        let twice_area: f64 = (0..n)
+            .map(|i| {
+                let (p, q) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+                p.x() * q.y() - q.x() * p.y()
+            })
+            .sum();
+        twice_area / 2.0
+    }
+    
+    /// Calculate the enclosed area (shoelace formula)
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+    
+    /// Split the polygon into triangles by ear clipping
+    ///
+    /// Assumes a simple (non-self-intersecting) polygon in either winding
+    /// order; the triangle areas then sum to the polygon's area. Collinear
+    /// vertices are dropped along the way. O(n³) in the worst case, which
+    /// is fine for hand-built shapes.
+    pub fn triangulate(&self) -> Vec<Triangle> {
+        if self.vertices.len() < 3 {
+            return Vec::new();
+        }
+        
+        // Work on indices in counterclockwise order
+        let mut remaining: Vec<usize> = (0..self.vertices.len()).collect();
+        if self.signed_area() < 0.0 {
+            remaining.reverse();
+        }
+        
+        let mut triangles = Vec::with_capacity(self.vertices.len() - 2);
+        while remaining.len() > 3 {
+            match self.find_ear(&remaining) {
+                Some(i) => {
+                    let n = remaining.len();
+                    let (prev, cur, next) = (remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]);
+                    triangles.push(Triangle::new(self.vertices[prev], self.vertices[cur], self.vertices[next]));
+                    remaining.remove(i);
+                }
+                None => {
+                    // No ear means degenerate geometry: drop a collinear vertex
+                    // (it adds no area), or give up if there is none
+                    match self.find_collinear(&remaining) {
+                        Some(i) => { remaining.remove(i); }
+                        None => return triangles,
+                    }
+                }
+            }
+        }
+        
+        let [a, b, c] = [remaining[0], remaining[1], remaining[2]];
+        if cross(&self.vertices[a], &self.vertices[b], &self.vertices[c]) != 0.0 {
+            triangles.push(Triangle::new(self.vertices[a], self.vertices[b], self.vertices[c]));
+        }
+        triangles
+    }
+    
+    /// Position in `remaining` of a convex vertex whose triangle contains
+    /// no other remaining vertex
+    fn find_ear(&self, remaining: &[usize]) -> Option<usize> {
+        let n = remaining.len();
+        (0..n).find(|&i| {
+            let prev = &self.vertices[remaining[(i + n - 1) % n]];
+            let cur = &self.vertices[remaining[i]];
+            let next = &self.vertices[remaining[(i + 1) % n]];
+            
+            if cross(prev, cur, next) <= 0.0 {
+                return false; // reflex or collinear
+            }
+            
+            remaining.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i && j != (i + n - 1) % n && j != (i + 1) % n)
+                .map(|(_, &k)| &self.vertices[k])
+                .filter(|p| *p != prev && *p != cur && *p != next)
+                .all(|p| !(cross(prev, cur, p) >= 0.0 && cross(cur, next, p) >= 0.0 && cross(next, prev, p) >= 0.0))
+        })
+    }
+    
+    /// Position in `remaining` of a vertex collinear with its neighbours
+    fn find_collinear(&self, remaining: &[usize]) -> Option<usize> {
+        let n = remaining.len();
+        (0..n).find(|&i| {
+            cross(
+                &self.vertices[remaining[(i + n - 1) % n]],
+                &self.vertices[remaining[i]],
+                &self.vertices[remaining[(i + 1) % n]],
+            ) == 0.0
+        })
+    }
+}
+
+impl Measurable for Polygon {
+    fn extent(&self) -> f64 {
+        self.perimeter()
+    }
+}
+
+impl Shape for Polygon {
+    fn area(&self) -> f64 {
+        Polygon::area(self)
+    }
+    
+    fn perimeter(&self) -> f64 {
+        Polygon::perimeter(self)
+    }
+    
+    fn name(&self) -> &str {
+        "Polygon"
+    }
+}
+
+impl fmt::Display for Polygon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polygon[")?;
+        for (i, vertex) in self.vertices.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", vertex)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total_area(triangles: &[Triangle]) -> f64 {
+        triangles.iter().map(|t| t.area()).sum()
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        assert_eq!(square.area(), 4.0);
+
+        let mut clockwise = square.vertices().to_vec();
+        clockwise.reverse();
+        assert_eq!(Polygon::new(clockwise).area(), 4.0);
+
+        assert_eq!(Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]).area(), 0.0);
+    }
+
+    #[test]
+    fn test_triangulate_convex_quad() {
+        let quad = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(5.0, 3.0),
+            Point::new(1.0, 2.0),
+        ]);
+        let triangles = quad.triangulate();
+
+        assert_eq!(triangles.len(), 2);
+        assert!((total_area(&triangles) - quad.area()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_triangulate_concave_l_shape() {
+        // L-shape of area 3, listed clockwise to exercise the reorientation
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(1.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.0, 0.0),
+        ]);
+        let triangles = l_shape.triangulate();
+
+        assert_eq!(l_shape.area(), 3.0);
+        assert_eq!(triangles.len(), 4);
+        assert!((total_area(&triangles) - 3.0).abs() < 1e-12);
+        for t in &triangles {
+            assert!(t.area() > 0.0);
+            // No triangle may cover the notch at (1.5, 1.5)
+            let [a, b, c] = t.vertices();
+            let p = Point::new(1.5, 1.5);
+            let inside = cross(&a, &b, &p) > 0.0 && cross(&b, &c, &p) > 0.0 && cross(&c, &a, &p) > 0.0;
+            assert!(!inside);
+        }
+    }
+
+    #[test]
+    fn test_triangulate_degenerate() {
+        assert!(Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)]).triangulate().is_empty());
+
+        // A collinear vertex in the middle of an edge adds no triangle area
+        let with_midpoint = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        assert!((total_area(&with_midpoint.triangulate()) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let corners = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let array = Array::from_vec(corners.clone());
+        
+        let polygon = array.to_polygon();
+        assert_eq!(polygon.vertices(), corners.as_slice());
+        assert_eq!(polygon.area(), 1.0);
+        assert_eq!(polygon.to_array(), array);
+    }
+
+    #[test]
+    fn test_unit_square_area_and_perimeter() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]);
+        
+        assert_eq!(square.area(), 1.0);
+        assert_eq!(square.perimeter(), 4.0);
+        assert_eq!(square.extent(), 4.0);
+        
+        let segment = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)]);
+        assert_eq!(segment.area(), 0.0);
+        assert_eq!(segment.perimeter(), 10.0); // there and back
+        assert_eq!(Polygon::new(Vec::new()).perimeter(), 0.0);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        // Concave L-shape
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        
+        assert!(l_shape.contains_point(&Point::new(0.5, 0.5)));
+        assert!(l_shape.contains_point(&Point::new(1.5, 0.5)));
+        assert!(l_shape.contains_point(&Point::new(0.5, 1.5)));
+        assert!(!l_shape.contains_point(&Point::new(1.5, 1.5))); // in the notch
+        assert!(!l_shape.contains_point(&Point::new(-1.0, 0.5)));
+        assert!(!l_shape.contains_point(&Point::new(3.0, 1.0))); // ray through a vertex
+        
+        assert!(!Polygon::new(vec![Point::new(0.0, 0.0)]).contains_point(&Point::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_to_geojson_closes_ring() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]);
+        assert_eq!(
+            square.to_geojson(),
+            r#"{"type":"Polygon","coordinates":[[[0.0,0.0],[1.0,0.0],[1.0,1.0],[0.0,1.0],[0.0,0.0]]]}"#
+        );
+        assert_eq!(Polygon::new(vec![]).to_geojson(), r#"{"type":"Polygon","coordinates":[[]]}"#);
+    }
+}
@@ -0,0 +1,164 @@
+// Rectangle class in CAD namespace - paul_lopez::cad::Rectangle
+// ============================================================
+#![allow(dead_code)]
+
+use super::Point; // Use Point from same CAD module
+use super::AABB;
+use super::traits::{Measurable, Shape};
+use std::fmt;
+
+/// Axis-aligned rectangle defined by two opposite corners
+///
+/// A shape wrapped around an AABB: the box does the corner bookkeeping
+/// and the overlap tests, the rectangle adds area, perimeter and Shape.
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    bounds: AABB,
+}
+
+impl Rectangle {
+    /// Create a rectangle from two opposite corners (in any order)
+    pub fn new(a: Point, b: Point) -> Self {
+        Rectangle { bounds: AABB::new(a, b) }
+    }
+    
+    /// Get the lower-left corner
+    pub fn min(&self) -> &Point {
+        self.bounds.min()
+    }
+    
+    /// Get the upper-right corner
+    pub fn max(&self) -> &Point {
+        self.bounds.max()
+    }
+    
+    /// Get the underlying bounding box
+    pub fn to_aabb(self) -> AABB {
+        self.bounds
+    }
+    
+    /// Get the width (extent along x)
+    pub fn width(&self) -> f64 {
+        self.bounds.width()
+    }
+    
+    /// Get the height (extent along y)
+    pub fn height(&self) -> f64 {
+        self.bounds.height()
+    }
+    
+    /// Calculate the area
+    pub fn area(&self) -> f64 {
+        super::rectangle_area(self.width(), self.height())
+    }
+    
+    /// Calculate the perimeter
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.width() + self.height())
+    }
+    
+    /// Get the center point
+    pub fn center(&self) -> Point {
+        self.min().midpoint(self.max())
+    }
+    
+    /// Check if a point is inside or on the edge of the rectangle
+    pub fn contains_point(&self, point: &Point) -> bool {
+        self.bounds.contains_point(point)
+    }
+    
+    /// Check if two rectangles overlap (touching edges count)
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.bounds.intersects(&other.bounds)
+    }
+}
+
+impl From<AABB> for Rectangle {
+    fn from(bounds: AABB) -> Self {
+        Rectangle { bounds }
+    }
+}
+
+impl Measurable for Rectangle {
+    fn extent(&self) -> f64 {
+        self.perimeter()
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        Rectangle::area(self)
+    }
+    
+    fn perimeter(&self) -> f64 {
+        Rectangle::perimeter(self)
+    }
+    
+    fn name(&self) -> &str {
+        "Rectangle"
+    }
+}
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rectangle[min: {}, max: {}]", self.min(), self.max())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rectangle_measures() {
+        let rect = Rectangle::new(Point::new(1.0, 1.0), Point::new(3.0, 4.0));
+        
+        assert_eq!(rect.width(), 2.0);
+        assert_eq!(rect.height(), 3.0);
+        assert_eq!(rect.area(), 6.0);
+        assert_eq!(rect.perimeter(), 10.0);
+        assert_eq!(rect.center(), Point::new(2.0, 2.5));
+    }
+
+    #[test]
+    fn test_rectangle_normalizes_corners() {
+        let a = Rectangle::new(Point::new(3.0, 4.0), Point::new(1.0, 2.0));
+        let b = Rectangle::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0));
+        let c = Rectangle::new(Point::new(1.0, 4.0), Point::new(3.0, 2.0));
+        
+        assert_eq!(a, b);
+        assert_eq!(b, c);
+        assert_eq!(*a.min(), Point::new(1.0, 2.0));
+        assert_eq!(a.width(), 2.0);
+    }
+
+    #[test]
+    fn test_rectangle_contains_and_intersects() {
+        let rect = Rectangle::new(Point::new(0.0, 0.0), Point::new(2.0, 3.0));
+        
+        assert!(rect.contains_point(&Point::new(1.0, 1.0)));
+        assert!(rect.contains_point(&Point::new(2.0, 3.0))); // corner
+        assert!(!rect.contains_point(&Point::new(2.5, 1.0)));
+        
+        let overlapping = Rectangle::new(Point::new(1.0, 2.0), Point::new(4.0, 5.0));
+        let touching = Rectangle::new(Point::new(2.0, 0.0), Point::new(3.0, 1.0));
+        let apart = Rectangle::new(Point::new(5.0, 5.0), Point::new(6.0, 6.0));
+        
+        assert!(rect.intersects(&overlapping));
+        assert!(overlapping.intersects(&rect));
+        assert!(rect.intersects(&touching));
+        assert!(!rect.intersects(&apart));
+    }
+
+    #[test]
+    fn test_rectangle_aabb_round_trip() {
+        let bounds = AABB::new(Point::new(-1.0, 2.0), Point::new(3.0, 5.0));
+        let rect = Rectangle::from(bounds);
+        
+        assert_eq!(rect.to_aabb(), bounds);
+        assert_eq!(rect, Rectangle::new(Point::new(3.0, 2.0), Point::new(-1.0, 5.0)));
+        assert_eq!(rect.width(), bounds.width());
+        assert_eq!(rect.area(), 12.0);
+    }
+}
@@ -0,0 +1,98 @@
+// Comparison tolerance in CAD namespace - paul_lopez::cad::tolerance
+// ==================================================================
+//
+// A process-wide default epsilon for approximate floating-point
+// comparisons. The value is stored as raw f64 bits in an AtomicU64 so it
+// can be changed at runtime without locks or unsafe code.
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Epsilon used until set_default_epsilon is called
+pub const DEFAULT_EPSILON: f64 = 1e-10;
+
+static DEFAULT_EPSILON_BITS: AtomicU64 = AtomicU64::new(DEFAULT_EPSILON.to_bits());
+
+/// Get the current default epsilon
+pub fn default_epsilon() -> f64 {
+    f64::from_bits(DEFAULT_EPSILON_BITS.load(Ordering::Relaxed))
+}
+
+/// Set the default epsilon used by approx_eq_default, assert_point_approx_eq!
+/// and the on-boundary / on-segment / tangency tests of Circle, Line and
+/// algorithms
+pub fn set_default_epsilon(eps: f64) {
+    DEFAULT_EPSILON_BITS.store(eps.abs().to_bits(), Ordering::Relaxed);
+}
+
+/// Restore the default epsilon to DEFAULT_EPSILON
+pub fn reset_default_epsilon() {
+    set_default_epsilon(DEFAULT_EPSILON);
+}
+
+/// Assert that two points are equal within an epsilon
+///
+/// Uses the global default epsilon unless one is given explicitly:
+/// `assert_point_approx_eq!(a, b)` or `assert_point_approx_eq!(a, b, 1e-6)`
+#[macro_export]
+macro_rules! assert_point_approx_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_point_approx_eq!(
+            $left,
+            $right,
+            $crate::paul_lopez::cad::tolerance::default_epsilon()
+        )
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {{
+        let (left, right, epsilon) = (&$left, &$right, $epsilon);
+        assert!(
+            left.approx_eq(right, epsilon),
+            "points not approximately equal\n  left: {}\n right: {}\n   eps: {:e}",
+            left, right, epsilon
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paul_lopez::cad::{Circle, Point};
+
+    // The epsilon is global and the circle, line and segment predicates
+    // read it, so everything that changes it lives in this single test and
+    // only loosens it slightly, keeping parallel tests from seeing a
+    // different answer.
+    #[test]
+    fn test_set_and_reset_default_epsilon() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(1.0 + 5e-8, 1.0 - 3e-8);
+
+        assert_eq!(default_epsilon(), DEFAULT_EPSILON);
+        assert!(!a.approx_eq_default(&b));
+        let circle = Circle::new(Point::ORIGIN, 1.0);
+        let near_boundary = Point::new(1.0 + 5e-8, 0.0);
+        assert!(!circle.point_on_boundary(&near_boundary));
+
+        set_default_epsilon(1e-7);
+        assert_eq!(default_epsilon(), 1e-7);
+        assert!(a.approx_eq_default(&b));
+        assert!(circle.point_on_boundary(&near_boundary));
+        assert_point_approx_eq!(a, b);
+
+        reset_default_epsilon();
+        assert_eq!(default_epsilon(), DEFAULT_EPSILON);
+        assert!(!a.approx_eq_default(&b));
+        assert!(!circle.point_on_boundary(&near_boundary));
+    }
+
+    #[test]
+    fn test_assert_macro_explicit_epsilon() {
+        assert_point_approx_eq!(Point::new(0.0, 0.0), Point::new(1e-7, -1e-7), 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "points not approximately equal")]
+    fn test_assert_macro_failure() {
+        assert_point_approx_eq!(Point::new(0.0, 0.0), Point::new(1.0, 0.0), 1e-6);
+    }
+}
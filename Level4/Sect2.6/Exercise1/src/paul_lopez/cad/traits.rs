@@ -0,0 +1,232 @@
+// Shared geometry traits in CAD namespace - paul_lopez::cad::traits
+// =================================================================
+//
+// C++ would express these as abstract base classes with pure virtual
+// functions; in Rust they are traits implemented by each CAD class.
+#![allow(dead_code)]
+
+use super::{Point, Vector2D}; // Use classes from same CAD module
+
+/// Something with a one-dimensional size: the length of a line or
+/// the distance around the boundary of a closed shape
+pub trait Measurable {
+    /// Length along the object (perimeter for closed shapes)
+    fn extent(&self) -> f64;
+}
+
+/// Common interface for closed and open shapes, so mixed shapes can be
+/// stored together as `Vec<Box<dyn Shape>>`
+///
+/// Not to be confused with the `cad::Shape` struct, which carries a
+/// shape's name, id and visibility.
+pub trait Shape {
+    /// Enclosed area (zero for open shapes such as lines)
+    fn area(&self) -> f64;
+    
+    /// Distance around the shape (the length for a line)
+    fn perimeter(&self) -> f64;
+    
+    /// Human-readable kind of shape, e.g. "Circle"
+    fn name(&self) -> &str;
+}
+
+/// Something that can be drawn on a terminal as ASCII art
+///
+/// World coordinates map to grid cells one unit per cell, rounded to the
+/// nearest cell, with (0, 0) the bottom-left cell and y growing upward.
+/// Lit cells are drawn as `#` and empty ones as `.`; anything outside the
+/// `width` x `height` grid is clipped.
+pub trait Drawable {
+    /// Integer cells covered by the object, before clipping
+    fn raster_cells(&self) -> Vec<(i64, i64)>;
+    
+    /// Draw onto a `width` x `height` grid, one line per row, top row first
+    fn render_ascii(&self, width: usize, height: usize) -> String {
+        let mut grid = vec![vec!['.'; width]; height];
+        for (x, y) in self.raster_cells() {
+            // Negative cells fail the conversion and are clipped too
+            if let (Ok(col), Ok(row)) = (usize::try_from(x), usize::try_from(y)) {
+                if col < width && row < height {
+                    grid[height - 1 - row][col] = '#';
+                }
+            }
+        }
+        
+        let mut output = String::with_capacity((width + 1) * height);
+        for row in grid {
+            output.extend(row);
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Something that can be resized about a fixed point
+///
+/// `scale` works about the origin, so a point's distance from the origin,
+/// a line's length and a circle's radius all change by |factor|.
+pub trait Scalable {
+    /// Scale about `pivot`: the pivot stays put, everything else moves
+    /// `factor` times as far from it
+    fn scale_about(&self, pivot: &Point, factor: f64) -> Self
+    where
+        Self: Sized;
+    
+    /// Scale about the origin
+    fn scale(&self, factor: f64) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_about(&Point::ORIGIN, factor)
+    }
+}
+
+/// Something that can be moved without changing its size or shape
+pub trait Translatable {
+    /// Copy moved by the given offset
+    fn translate(&self, dx: f64, dy: f64) -> Self
+    where
+        Self: Sized;
+    
+    /// Copy moved by a displacement vector
+    fn translate_by(&self, v: &Vector2D) -> Self
+    where
+        Self: Sized,
+    {
+        self.translate(v.dx(), v.dy())
+    }
+}
+
+/// Sum the extents of a heterogeneous collection
+pub fn total_extent(items: &[&dyn Measurable]) -> f64 {
+    items.iter().map(|item| item.extent()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paul_lopez::cad::{Line, Circle};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_total_extent_heterogeneous() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(3.0, 4.0));
+        let circle = Circle::new(Point::new(1.0, 1.0), 2.0);
+
+        let items: Vec<&dyn Measurable> = vec![&line, &circle, &line];
+        let expected = 5.0 + 4.0 * PI + 5.0;
+
+        assert!((total_extent(&items) - expected).abs() < 1e-12);
+        assert_eq!(total_extent(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_boxed_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Circle::new(Point::new(0.0, 0.0), 1.0)),
+            Box::new(Line::new(Point::new(0.0, 0.0), Point::new(3.0, 4.0))),
+        ];
+
+        let total_perimeter: f64 = shapes.iter().map(|s| s.perimeter()).sum();
+        let total_area: f64 = shapes.iter().map(|s| s.area()).sum();
+        let names: Vec<&str> = shapes.iter().map(|s| s.name()).collect();
+
+        assert!((total_perimeter - (2.0 * PI + 5.0)).abs() < 1e-12);
+        assert!((total_area - PI).abs() < 1e-12);
+        assert_eq!(names, vec!["Circle", "Line"]);
+    }
+
+    fn lit_cells(ascii: &str) -> usize {
+        ascii.chars().filter(|&c| c == '#').count()
+    }
+
+    #[test]
+    fn test_render_point_and_line() {
+        assert_eq!(Point::new(1.2, 0.9).render_ascii(3, 2), ".#.\n...\n");
+
+        let diagonal = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        assert_eq!(diagonal.render_ascii(3, 3), "..#\n.#.\n#..\n");
+    }
+
+    #[test]
+    fn test_render_circle() {
+        let circle = Circle::new(Point::new(4.0, 4.0), 2.0);
+        let ascii = circle.render_ascii(9, 9);
+        assert_eq!(lit_cells(&ascii), 12);
+        assert_eq!(ascii.lines().count(), 9);
+        assert!(ascii.lines().all(|row| row.len() == 9));
+        assert_eq!(ascii.lines().nth(2), Some("...###..."));
+    }
+
+    #[test]
+    fn test_render_clips_to_grid() {
+        // Only the right half of the circle (x >= 0) fits
+        let circle = Circle::new(Point::new(0.0, 4.0), 2.0);
+        assert_eq!(lit_cells(&circle.render_ascii(9, 9)), 7);
+
+        let line = Line::new(Point::new(-5.0, 1.0), Point::new(20.0, 1.0));
+        assert_eq!(line.render_ascii(4, 2), "####\n....\n");
+
+        assert_eq!(Point::new(-1.0, 0.0).render_ascii(2, 1), "..\n");
+        assert_eq!(Point::new(0.0, 0.0).render_ascii(0, 0), "");
+    }
+
+    fn scaled_by_two<T: Scalable>(item: &T) -> T {
+        item.scale(2.0)
+    }
+
+    #[test]
+    fn test_scalable_doubles_magnitudes() {
+        let point = Point::new(3.0, 4.0);
+        assert_eq!(scaled_by_two(&point).distance_to_origin(), 10.0);
+
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(4.0, 5.0));
+        assert_eq!(scaled_by_two(&line).length(), 10.0);
+
+        let circle = Circle::new(Point::new(1.0, -2.0), 1.5);
+        let scaled = scaled_by_two(&circle);
+        assert_eq!(scaled.radius(), 3.0);
+        assert_eq!(*scaled.center(), Point::new(2.0, -4.0));
+
+        // A plain method call resolves to the trait and moves the center too
+        let scaled = Circle::new(Point::new(1.0, 2.0), 3.0).scale(2.0);
+        assert_eq!(*scaled.center(), Point::new(2.0, 4.0));
+        assert_eq!(scaled.radius(), 6.0);
+    }
+
+    #[test]
+    fn test_scalable_about_pivot() {
+        let pivot = Point::new(1.0, 1.0);
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(2.0, 3.0));
+        let scaled = Scalable::scale_about(&line, &pivot, 3.0);
+        assert_eq!(scaled, Line::new(Point::new(1.0, 1.0), Point::new(4.0, 7.0)));
+
+        // Negative factors mirror through the pivot; a radius stays positive
+        let circle = Circle::new(Point::new(3.0, 1.0), 2.0);
+        let flipped = Scalable::scale_about(&circle, &pivot, -0.5);
+        assert_eq!(flipped, Circle::new(Point::new(0.0, 1.0), 1.0));
+    }
+
+    fn shift_all<T: Translatable>(items: &[T], v: &Vector2D) -> Vec<T> {
+        items.iter().map(|item| item.translate_by(v)).collect()
+    }
+
+    #[test]
+    fn test_translatable_moves_reference_points() {
+        let v = Vector2D::new(2.0, -1.0);
+
+        let points = shift_all(&[Point::new(0.0, 0.0), Point::new(1.0, 1.0)], &v);
+        assert_eq!(points, vec![Point::new(2.0, -1.0), Point::new(3.0, 0.0)]);
+
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(4.0, 5.0));
+        let moved = Translatable::translate(&line, 2.0, -1.0);
+        assert_eq!(*moved.start(), Point::new(3.0, 0.0));
+        assert_eq!(*moved.end(), Point::new(6.0, 4.0));
+        assert_eq!(moved.length(), line.length());
+
+        let circle = Circle::new(Point::new(-1.0, 2.0), 1.5);
+        let moved = &shift_all(&[circle], &v)[0];
+        assert_eq!(*moved.center(), Point::new(1.0, 1.0));
+        assert_eq!(moved.radius(), circle.radius());
+    }
+}
@@ -0,0 +1,170 @@
+// Linear transforms in CAD namespace - paul_lopez::cad::Matrix2x2
+// ==============================================================
+//
+// Rotation, scaling and shear expressed as a 2x2 matrix so they can be
+// composed and applied to points in one place:
+//
+//   | a  b |   | x |   | a*x + b*y |
+//   | c  d | * | y | = | c*x + d*y |
+#![allow(dead_code)]
+
+use super::Point; // Use Point from same CAD module
+use std::fmt;
+use std::ops::Mul;
+
+/// 2x2 matrix for linear transforms of points
+///
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix2x2 {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+}
+
+impl Matrix2x2 {
+    /// Create a matrix from its entries in row-major order
+    pub fn new(a: f64, b: f64, c: f64, d: f64) -> Self {
+        Matrix2x2 { a, b, c, d }
+    }
+    
+    /// The identity transform
+    pub fn identity() -> Self {
+        Matrix2x2::new(1.0, 0.0, 0.0, 1.0)
+    }
+    
+    /// Counterclockwise rotation about the origin (angle in radians)
+    pub fn rotation(theta: f64) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Matrix2x2::new(cos, -sin, sin, cos)
+    }
+    
+    /// Scaling about the origin by separate x and y factors
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Matrix2x2::new(sx, 0.0, 0.0, sy)
+    }
+    
+    /// Get the top-left entry
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+    
+    /// Get the top-right entry
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+    
+    /// Get the bottom-left entry
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+    
+    /// Get the bottom-right entry
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+    
+    /// Calculate the determinant (ad - bc)
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+    
+    /// Get the inverse transform
+    /// Returns None for a singular matrix (zero determinant)
+    pub fn inverse(&self) -> Option<Matrix2x2> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        Some(Matrix2x2::new(self.d / det, -self.b / det, -self.c / det, self.a / det))
+    }
+}
+
+impl Default for Matrix2x2 {
+    fn default() -> Self {
+        Matrix2x2::identity()
+    }
+}
+
+impl fmt::Display for Matrix2x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Matrix2x2[[{:.2}, {:.2}], [{:.2}, {:.2}]]", self.a(), self.b(), self.c(), self.d())
+    }
+}
+
+/// Apply the transform: matrix * point
+impl Mul<Point> for Matrix2x2 {
+    type Output = Point;
+    
+    fn mul(self, p: Point) -> Self::Output {
+        Point::new(self.a() * p.x() + self.b() * p.y(), self.c() * p.x() + self.d() * p.y())
+    }
+}
+
+/// Compose transforms: (m1 * m2) * p == m1 * (m2 * p)
+impl Mul for Matrix2x2 {
+    type Output = Matrix2x2;
+    
+    fn mul(self, o: Matrix2x2) -> Self::Output {
+        Matrix2x2::new(
+            self.a() * o.a() + self.b() * o.c(),
+            self.a() * o.b() + self.b() * o.d(),
+            self.c() * o.a() + self.d() * o.c(),
+            self.c() * o.b() + self.d() * o.d(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_entries_and_display() {
+        let m = Matrix2x2::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!((m.a(), m.b(), m.c(), m.d()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(m.determinant(), -2.0);
+        assert_eq!(m.to_string(), "Matrix2x2[[1.00, 2.00], [3.00, 4.00]]");
+    }
+
+    #[test]
+    fn test_rotation() {
+        let rotated = Matrix2x2::rotation(FRAC_PI_2) * Point::new(1.0, 0.0);
+        assert!(rotated.approx_eq(&Point::new(0.0, 1.0), 1e-12));
+
+        // Matches Point::rotate
+        let p = Point::new(2.0, -3.0);
+        assert!((Matrix2x2::rotation(0.7) * p).approx_eq(&p.rotate(0.7), 1e-12));
+        assert!((Matrix2x2::rotation(0.7).determinant() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_undoes_scaling() {
+        let scale = Matrix2x2::scaling(2.0, -4.0);
+        let p = Point::new(1.5, 3.0);
+
+        let scaled = scale * p;
+        assert_eq!(scaled, Point::new(3.0, -12.0));
+        assert_eq!(scale.inverse().unwrap() * scaled, p);
+        assert_eq!(scale * scale.inverse().unwrap(), Matrix2x2::identity());
+    }
+
+    #[test]
+    fn test_singular_has_no_inverse() {
+        assert_eq!(Matrix2x2::new(1.0, 2.0, 2.0, 4.0).inverse(), None);
+        assert_eq!(Matrix2x2::scaling(0.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn test_composition() {
+        let p = Point::new(1.0, 2.0);
+        let rotate = Matrix2x2::rotation(FRAC_PI_2);
+        let scale = Matrix2x2::scaling(3.0, 1.0);
+
+        assert!(((rotate * scale) * p).approx_eq(&(rotate * (scale * p)), 1e-12));
+        assert_eq!(Matrix2x2::default() * p, p);
+    }
+}
@@ -0,0 +1,56 @@
+// Triangle class in CAD namespace - paul_lopez::cad::Triangle
+// ==========================================================
+#![allow(dead_code)]
+
+use super::Point; // Use Point from same CAD module
+use super::algorithms::cross;
+use std::fmt;
+
+/// Triangle class defined by three vertices
+///
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    a: Point,
+    b: Point,
+    c: Point,
+}
+
+impl Triangle {
+    /// Create a triangle from three vertices
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        Triangle { a, b, c }
+    }
+    
+    /// Get the three vertices in construction order
+    pub fn vertices(&self) -> [Point; 3] {
+        [self.a, self.b, self.c]
+    }
+    
+    /// Calculate the area (always non-negative)
+    pub fn area(&self) -> f64 {
+        cross(&self.a, &self.b, &self.c).abs() / 2.0
+    }
+}
+
+impl fmt::Display for Triangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Triangle[{}, {}, {}]", self.a, self.b, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle_area() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(0.0, 3.0);
+
+        assert_eq!(Triangle::new(a, b, c).area(), 6.0);
+        assert_eq!(Triangle::new(a, c, b).area(), 6.0);
+        assert_eq!(Triangle::new(a, b, c).vertices(), [a, b, c]);
+    }
+}
@@ -0,0 +1,199 @@
+// Vector2D class in CAD namespace - paul_lopez::cad::Vector2D
+// ==========================================================
+//
+// A displacement (direction and length) as opposed to a Point, which is a
+// location. Point + Vector2D moves a point; Vector2D::between gives the
+// displacement from one point to another.
+#![allow(dead_code)]
+
+use super::Point; // Use Point from same CAD module
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// 2D displacement vector
+///
+/// Located in the paul_lopez::cad namespace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2D {
+    dx: f64,
+    dy: f64,
+}
+
+impl Vector2D {
+    /// Create a new vector with given components
+    pub fn new(dx: f64, dy: f64) -> Self {
+        Vector2D { dx, dy }
+    }
+    
+    /// Displacement that moves `from` onto `to`
+    pub fn between(from: &Point, to: &Point) -> Self {
+        (*to - *from).into()
+    }
+    
+    /// Get the x component
+    pub fn dx(&self) -> f64 {
+        self.dx
+    }
+    
+    /// Get the y component
+    pub fn dy(&self) -> f64 {
+        self.dy
+    }
+    
+    /// Dot product
+    pub fn dot(&self, other: &Vector2D) -> f64 {
+        self.dx * other.dx + self.dy * other.dy
+    }
+    
+    /// 2D cross product (z component); positive if `other` is counterclockwise
+    pub fn cross(&self, other: &Vector2D) -> f64 {
+        self.dx * other.dy - self.dy * other.dx
+    }
+    
+    /// Get the length of the vector
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    
+    /// Get the unit vector in the same direction
+    /// Returns None for the zero vector
+    pub fn normalized(&self) -> Option<Vector2D> {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            None
+        } else {
+            Some(Vector2D::new(self.dx / magnitude, self.dy / magnitude))
+        }
+    }
+}
+
+impl Default for Vector2D {
+    fn default() -> Self {
+        Vector2D::new(0.0, 0.0)
+    }
+}
+
+impl fmt::Display for Vector2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "Vector2D({:.*}, {:.*})", precision, self.dx, precision, self.dy)
+    }
+}
+
+// Arithmetic operators
+
+/// Vector addition: v1 + v2
+impl Add for Vector2D {
+    type Output = Vector2D;
+    
+    fn add(self, other: Vector2D) -> Self::Output {
+        Vector2D::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+/// Vector subtraction: v1 - v2
+impl Sub for Vector2D {
+    type Output = Vector2D;
+    
+    fn sub(self, other: Vector2D) -> Self::Output {
+        Vector2D::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+/// Scalar multiplication: vector * factor
+impl Mul<f64> for Vector2D {
+    type Output = Vector2D;
+    
+    fn mul(self, factor: f64) -> Self::Output {
+        Vector2D::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+/// Reverse direction: -vector
+impl Neg for Vector2D {
+    type Output = Vector2D;
+    
+    fn neg(self) -> Self::Output {
+        Vector2D::new(-self.dx, -self.dy)
+    }
+}
+
+/// Move a point: point + vector
+impl Add<Vector2D> for Point {
+    type Output = Point;
+    
+    fn add(self, vector: Vector2D) -> Self::Output {
+        Point::new(self.x() + vector.dx, self.y() + vector.dy)
+    }
+}
+
+/// Move a point backwards: point - vector
+impl Sub<Vector2D> for Point {
+    type Output = Point;
+    
+    fn sub(self, vector: Vector2D) -> Self::Output {
+        Point::new(self.x() - vector.dx, self.y() - vector.dy)
+    }
+}
+
+// Conversions between the two roles
+
+/// Position vector of a point (its displacement from the origin)
+impl From<Point> for Vector2D {
+    fn from(point: Point) -> Self {
+        Vector2D::new(point.x(), point.y())
+    }
+}
+
+/// Point reached by applying the vector to the origin
+impl From<Vector2D> for Point {
+    fn from(vector: Vector2D) -> Self {
+        Point::new(vector.dx, vector.dy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_plus_vector() {
+        let p = Point::new(1.0, 2.0);
+        let v = Vector2D::new(3.0, -1.0);
+
+        assert_eq!(p + v, Point::new(4.0, 1.0));
+        assert_eq!(p + v - v, p);
+    }
+
+    #[test]
+    fn test_vector_between_points() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(4.0, 5.0);
+
+        let v = Vector2D::between(&a, &b);
+        assert_eq!(v, Vector2D::new(3.0, 4.0));
+        assert_eq!(v.magnitude(), 5.0);
+        assert_eq!(a + v, b);
+
+        // Point - Point yields a Point, which converts into the same vector
+        let w: Vector2D = (b - a).into();
+        assert_eq!(w, v);
+    }
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let v = Vector2D::new(1.0, 2.0);
+        let w = Vector2D::new(3.0, 4.0);
+
+        assert_eq!(v + w, Vector2D::new(4.0, 6.0));
+        assert_eq!(w - v, Vector2D::new(2.0, 2.0));
+        assert_eq!(v * 2.0, Vector2D::new(2.0, 4.0));
+        assert_eq!(-v, Vector2D::new(-1.0, -2.0));
+        assert_eq!(v.dot(&w), 11.0);
+        assert_eq!(v.cross(&w), -2.0);
+        assert_eq!(w.normalized(), Some(Vector2D::new(0.6, 0.8)));
+        assert_eq!(Vector2D::default().normalized(), None);
+        assert_eq!(Point::from(w), Point::new(3.0, 4.0));
+        assert_eq!(v.to_string(), "Vector2D(1.00, 2.00)");
+    }
+}
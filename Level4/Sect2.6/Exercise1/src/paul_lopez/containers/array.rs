@@ -5,6 +5,8 @@
 // C++: using PaulLopez::CAD::Point; (but we can use just CAD::Point since we're in PaulLopez)
 // Rust: Use full path or import from crate root
 use crate::paul_lopez::cad::Point; // Full namespace path as required by exercise
+use crate::paul_lopez::cad::{Line, Polygon};
+use crate::paul_lopez::error::GeometryError;
 
 use std::ops::{Index, IndexMut};
 use std::fmt;
@@ -38,27 +40,85 @@ impl Array {
         Array { data: points }
     }
     
+    /// Concatenate several arrays into one, keeping element order
+    pub fn concat(arrays: &[Array]) -> Self {
+        let total: usize = arrays.iter().map(|a| a.size()).sum();
+        let mut data = Vec::with_capacity(total);
+        for array in arrays {
+            data.extend_from_slice(&array.data);
+        }
+        Array { data }
+    }
+    
+    /// Treat the points as the vertices of a polygon, in order
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.data.clone())
+    }
+    
+    /// Write the points as CSV, one `x,y` row per point (no header)
+    pub fn to_csv(&self) -> String {
+        self.data.iter()
+            .map(|p| format!("{},{}\n", p.x(), p.y()))
+            .collect()
+    }
+    
+    /// Read points from `x,y` CSV rows, as written by to_csv
+    ///
+    /// A first row with no numeric fields (e.g. `x,y`) is skipped as a
+    /// header and blank lines are ignored. Any other row that is not exactly
+    /// two numbers is a ParseError naming its (1-based) line number.
+    pub fn from_csv(text: &str) -> Result<Array, GeometryError> {
+        let mut data = Vec::new();
+        let mut first_row = true;
+        
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            
+            let fields: Vec<Option<f64>> = line.split(',')
+                .map(|field| field.trim().parse().ok())
+                .collect();
+            let is_header = first_row && fields.iter().all(Option::is_none);
+            first_row = false;
+            
+            match fields[..] {
+                [Some(x), Some(y)] => data.push(Point::new(x, y)),
+                _ if is_header => {}
+                _ => {
+                    return Err(GeometryError::ParseError(format!(
+                        "line {}: expected two numeric columns, found \"{}\"", index + 1, line
+                    )));
+                }
+            }
+        }
+        
+        Ok(Array { data })
+    }
+    
     /// Get the size of the array
     pub fn size(&self) -> usize {
         self.data.len()
     }
     
     /// Set element at given index with bounds checking
-    pub fn set_element(&mut self, index: usize, point: Point) {
-        if index < self.data.len() {
-            self.data[index] = point;
+    /// Returns IndexOutOfBounds (and leaves the array unchanged) if out of bounds
+    pub fn set_element(&mut self, index: usize, point: Point) -> Result<(), GeometryError> {
+        let len = self.data.len();
+        match self.data.get_mut(index) {
+            Some(slot) => {
+                *slot = point;
+                Ok(())
+            }
+            None => Err(GeometryError::IndexOutOfBounds { index, len }),
         }
-        // Ignore if out of bounds (as per exercise specification)
     }
     
     /// Get element at given index with bounds checking
-    /// Returns first element if out of bounds
-    pub fn get_element(&self, index: usize) -> Point {
-        if index < self.data.len() {
-            self.data[index]
-        } else {
-            self.data[0] // Return first element if out of bounds
-        }
+    /// Returns None if out of bounds
+    pub fn get_element(&self, index: usize) -> Option<&Point> {
+        self.data.get(index)
     }
     
     /// Check if array is empty
@@ -81,18 +141,77 @@ impl Array {
         self.data.pop()
     }
     
+    /// Insert element at given index, shifting later elements right
+    /// `index` may equal size() to append; anything larger is IndexOutOfBounds
+    pub fn insert(&mut self, index: usize, point: Point) -> Result<(), GeometryError> {
+        if index > self.data.len() {
+            return Err(GeometryError::IndexOutOfBounds { index, len: self.data.len() });
+        }
+        self.data.insert(index, point);
+        Ok(())
+    }
+    
+    /// Remove and return element at given index, shifting later elements left
+    pub fn remove(&mut self, index: usize) -> Result<Point, GeometryError> {
+        if index >= self.data.len() {
+            return Err(GeometryError::IndexOutOfBounds { index, len: self.data.len() });
+        }
+        Ok(self.data.remove(index))
+    }
+    
     /// Resize array to new size
     pub fn resize(&mut self, new_size: usize) {
         self.data.resize(new_size, Point::default());
     }
     
+    /// Get the number of points the array can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+    
+    /// Reserve room for at least `additional` more points
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+    
+    /// Approximate memory used by the array in bytes
+    ///
+    /// Counts the Array struct itself plus the heap buffer, which is sized
+    /// by capacity rather than by the number of points in use.
+    pub fn memory_footprint(&self) -> usize {
+        std::mem::size_of::<Array>() + self.data.capacity() * std::mem::size_of::<Point>()
+    }
+    
+    /// Put the array in canonical form: sort points lexicographically by
+    /// (x, y) and drop any point within `epsilon` of one already kept
+    ///
+    /// Two arrays holding the same points in any order normalize to the
+    /// same sequence, which makes results of order-insensitive algorithms
+    /// directly comparable.
+    pub fn normalize(&mut self, epsilon: f64) {
+        self.data.sort_by(|a, b| a.x().total_cmp(&b.x()).then(a.y().total_cmp(&b.y())));
+        
+        let mut kept: Vec<Point> = Vec::with_capacity(self.data.len());
+        for point in self.data.drain(..) {
+            // Sorted by x, so only the tail of kept can be within epsilon
+            let duplicate = kept.iter()
+                .rev()
+                .take_while(|k| point.x() - k.x() <= epsilon)
+                .any(|k| (point.y() - k.y()).abs() <= epsilon);
+            if !duplicate {
+                kept.push(point);
+            }
+        }
+        self.data = kept;
+    }
+    
     /// Get iterator over points
-    pub fn iter(&self) -> std::slice::Iter<Point> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Point> {
         self.data.iter()
     }
     
     /// Get mutable iterator over points
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<Point> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Point> {
         self.data.iter_mut()
     }
     
@@ -116,15 +235,43 @@ impl Array {
         }
     }
     
+    /// Iterate over the line segments joining consecutive points
+    pub fn segment_iter(&self) -> impl Iterator<Item = Line> + '_ {
+        self.data.windows(2)
+            .map(|window| Line::new(window[0], window[1]))
+    }
+    
     /// Calculate total distance traveled through all points in order
     pub fn total_path_distance(&self) -> f64 {
+        self.segment_iter()
+            .map(|segment| segment.length())
+            .sum()
+    }
+    
+    /// Get the mean length of the segments joining consecutive points
+    /// Returns None when there are fewer than two points
+    pub fn average_segment_length(&self) -> Option<f64> {
         if self.data.len() < 2 {
-            return 0.0;
+            return None;
         }
         
-        self.data.windows(2)
-            .map(|window| window[0].distance(&window[1]))
-            .sum()
+        let segment_count = self.data.len() - 1;
+        Some(self.total_path_distance() / segment_count as f64)
+    }
+    
+    /// Sort points by increasing distance from the origin (stable)
+    pub fn sort_by_distance_to_origin(&mut self) {
+        self.data.sort_by(|a, b| {
+            a.distance_to_origin_squared()
+                .total_cmp(&b.distance_to_origin_squared())
+        });
+    }
+    
+    /// Find the stored point closest to a target
+    /// Returns None if the array is empty; ties go to the earliest point
+    pub fn nearest_to(&self, target: &Point) -> Option<&Point> {
+        self.data.iter()
+            .min_by(|a, b| a.distance_squared(target).total_cmp(&b.distance_squared(target)))
     }
     
     /// Find the point farthest from origin
@@ -139,19 +286,52 @@ impl Array {
             .map(|(i, &p)| (i, p))
     }
     
+    /// Apply a reducer to every sliding window of `window` consecutive points
+    ///
+    /// Treats the array as a series and returns one value per window, so the
+    /// result has `size() - window + 1` entries. A window of zero or one
+    /// larger than the array yields an empty vector.
+    pub fn rolling<F>(&self, window: usize, reducer: F) -> Vec<f64>
+    where
+        F: Fn(&[Point]) -> f64,
+    {
+        if window == 0 || window > self.data.len() {
+            return Vec::new();
+        }
+        
+        self.data.windows(window)
+            .map(reducer)
+            .collect()
+    }
+    
     /// Get centroid (average) of all points
     pub fn centroid(&self) -> Point {
         if self.data.is_empty() {
             return Point::default();
         }
         
-        let sum = self.data.iter()
-            .fold(Point::new(0.0, 0.0), |acc, &p| acc + p);
-        
-        sum * (1.0 / self.data.len() as f64)
+        let sum: Point = self.data.iter().sum();
+        sum / self.data.len() as f64
     }
 }
 
+// Prebuilt reducers for Array::rolling
+
+/// Mean of the y coordinates in a window
+pub fn mean_y(window: &[Point]) -> f64 {
+    window.iter().map(|p| p.y()).sum::<f64>() / window.len() as f64
+}
+
+/// Largest y coordinate in a window
+pub fn max_y(window: &[Point]) -> f64 {
+    window.iter().map(|p| p.y()).fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// Smallest y coordinate in a window
+pub fn min_y(window: &[Point]) -> f64 {
+    window.iter().map(|p| p.y()).fold(f64::INFINITY, f64::min)
+}
+
 impl Default for Array {
     fn default() -> Self {
         Self::new()
@@ -184,14 +364,18 @@ impl IndexMut<usize> for Array {
 
 impl fmt::Display for Array {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Array[size: {}, points: [", self.size())?;
+        write!(f, "Array[")?;
         for (i, point) in self.data.iter().enumerate() {
             if i > 0 {
                 write!(f, ", ")?;
             }
-            write!(f, "{}", point)?;
+            // Pass {:.N} through to each point
+            match f.precision() {
+                Some(precision) => write!(f, "{:.*}", precision, point)?,
+                None => write!(f, "{}", point)?,
+            }
         }
-        write!(f, "]]")
+        write!(f, "]")
     }
 }
 
@@ -218,6 +402,34 @@ impl From<Array> for Vec<Point> {
     }
 }
 
+// Iteration: for p in array / for p in &array / for p in &mut array
+impl IntoIterator for Array {
+    type Item = Point;
+    type IntoIter = std::vec::IntoIter<Point>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Array {
+    type Item = &'a Point;
+    type IntoIter = std::slice::Iter<'a, Point>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Array {
+    type Item = &'a mut Point;
+    type IntoIter = std::slice::IterMut<'a, Point>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,16 +475,20 @@ mod tests {
         let mut array = Array::with_size(3);
         let test_point = Point::new(7.0, 8.0);
         
-        array.set_element(1, test_point);
-        assert_eq!(array.get_element(1), test_point);
+        assert_eq!(array.set_element(1, test_point), Ok(()));
+        assert_eq!(array.get_element(1), Some(&test_point));
         
         // Test bounds checking
-        let original_first = array[0];
-        array.set_element(10, Point::new(99.0, 99.0)); // Out of bounds
-        assert_eq!(array[0], original_first); // Should be unchanged
+        let before = array.clone();
+        let result = array.set_element(10, Point::new(99.0, 99.0)); // Out of bounds
+        assert_eq!(result, Err(GeometryError::IndexOutOfBounds { index: 10, len: 3 }));
+        assert_eq!(array, before); // Should be unchanged
+        assert_eq!(result.unwrap_err().to_string(), "index 10 out of bounds for array of size 3");
         
-        // Out of bounds get should return first element
-        assert_eq!(array.get_element(10), original_first);
+        // Out of bounds get reports the missing element
+        assert_eq!(array.get_element(10), None);
+        assert_eq!(array.get_element(3), None);
+        assert_eq!(Array::from_vec(Vec::new()).get_element(0), None);
     }
 
     #[test]
@@ -312,6 +528,216 @@ mod tests {
         assert_eq!(centroid, Point::new(3.0, 4.0));
     }
 
+    #[test]
+    fn test_segment_iter() {
+        let array = Array::from_vec(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+        ]);
+        
+        let segments: Vec<Line> = array.segment_iter().collect();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(*segments[1].start(), Point::new(1.0, 0.0));
+        assert_eq!(*segments[1].end(), Point::new(1.0, 1.0));
+        
+        assert_eq!(Array::with_size(1).segment_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_average_segment_length() {
+        // Equally spaced path along the x-axis
+        let path: Vec<Point> = (0..5).map(|i| Point::new(i as f64 * 2.5, 0.0)).collect();
+        let array = Array::from_vec(path);
+        
+        let average = array.average_segment_length().unwrap();
+        assert!((average - 2.5).abs() < 1e-10);
+        
+        assert_eq!(Array::with_size(1).average_segment_length(), None);
+        assert_eq!(Array::with_size(0).average_segment_length(), None);
+    }
+
+    #[test]
+    fn test_rolling_mean_y() {
+        // Price-like series: y values 1, 2, 3, 4, 5
+        let series: Vec<Point> = (1..=5).map(|i| Point::new(i as f64, i as f64)).collect();
+        let array = Array::from_vec(series);
+        
+        assert_eq!(array.rolling(3, mean_y), vec![2.0, 3.0, 4.0]);
+        assert_eq!(array.rolling(1, mean_y), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(array.rolling(5, mean_y), vec![3.0]);
+    }
+
+    #[test]
+    fn test_rolling_min_max_y() {
+        let array = Array::from_vec(vec![
+            Point::new(0.0, 3.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 4.0),
+            Point::new(3.0, 1.5),
+        ]);
+        
+        assert_eq!(array.rolling(2, max_y), vec![3.0, 4.0, 4.0]);
+        assert_eq!(array.rolling(2, min_y), vec![1.0, 1.0, 1.5]);
+        
+        // Custom reducer: range of x in the window
+        let spans = array.rolling(3, |w| w[w.len() - 1].x() - w[0].x());
+        assert_eq!(spans, vec![2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_rolling_window_too_large() {
+        let array = Array::with_size(3);
+        
+        assert!(array.rolling(4, mean_y).is_empty());
+        assert!(array.rolling(0, mean_y).is_empty());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut a = Array::from_vec(vec![
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 5.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 5.0),
+        ]);
+        let mut b = Array::from_vec(vec![
+            Point::new(0.0, 5.0 + 1e-12),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.0, 1.0),
+        ]);
+        
+        a.normalize(1e-9);
+        b.normalize(1e-9);
+        
+        let expected = Array::from_vec(vec![
+            Point::new(0.0, 5.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+        ]);
+        assert_eq!(a, expected);
+        assert_eq!(b.size(), 3);
+        for i in 0..3 {
+            assert!(b[i].distance(&expected[i]) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_normalize_non_adjacent_duplicates() {
+        // (0, 1) and (1e-12, 1) are duplicates but (1e-12, 0) sorts between them
+        let mut array = Array::from_vec(vec![
+            Point::new(1e-12, 1.0),
+            Point::new(1e-12, 0.0),
+            Point::new(0.0, 1.0),
+        ]);
+        array.normalize(1e-9);
+        
+        assert_eq!(array.size(), 2);
+        assert_eq!(array[0], Point::new(0.0, 1.0));
+        assert_eq!(array[1], Point::new(1e-12, 0.0));
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        let point_size = std::mem::size_of::<Point>();
+        let struct_size = std::mem::size_of::<Array>();
+        
+        let empty = Array::from_vec(Vec::new());
+        assert_eq!(empty.memory_footprint(), struct_size);
+        
+        let mut array = Array::with_size(4);
+        let before = array.memory_footprint();
+        assert_eq!(before, struct_size + array.capacity() * point_size);
+        
+        array.reserve(100);
+        assert!(array.capacity() >= 104);
+        assert!(array.memory_footprint() > before);
+        assert_eq!(array.memory_footprint(), struct_size + array.capacity() * point_size);
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = Array::from_vec(vec![Point::new(1.0, 1.0)]);
+        let b = Array::from_vec(vec![Point::new(2.0, 2.0), Point::new(3.0, 3.0)]);
+        let c = Array::from_vec(vec![Point::new(4.0, 4.0)]);
+        
+        let joined = Array::concat(&[a, b, c]);
+        assert_eq!(joined.size(), 4);
+        assert!(joined.capacity() >= 4);
+        for (i, point) in joined.iter().enumerate() {
+            assert_eq!(*point, Point::new((i + 1) as f64, (i + 1) as f64));
+        }
+        
+        assert!(Array::concat(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let array = Array::from_vec(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 4.0),
+            Point::new(5.0, 6.0),
+        ]);
+        
+        let mut by_ref = 0.0;
+        for p in &array {
+            by_ref += p.x() + p.y();
+        }
+        assert_eq!(by_ref, 21.0);
+        
+        let mut copy = array.clone();
+        for p in &mut copy {
+            *p = -*p;
+        }
+        assert_eq!(copy[2], Point::new(-5.0, -6.0));
+        
+        let xs: Vec<f64> = array.into_iter().filter(|p| p.x() > 2.0).map(|p| p.x()).collect();
+        assert_eq!(xs, vec![3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_sort_by_distance_to_origin() {
+        let mut array = Array::from_vec(vec![
+            Point::new(3.0, 4.0),
+            Point::new(-1.0, 0.0),
+            Point::new(0.0, -10.0),
+            Point::new(1.0, 1.0),
+        ]);
+        array.sort_by_distance_to_origin();
+        
+        assert_eq!(array, Array::from_vec(vec![
+            Point::new(-1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 4.0),
+            Point::new(0.0, -10.0),
+        ]));
+    }
+
+    #[test]
+    fn test_nearest_to() {
+        let array = Array::from_vec(vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 5.0),
+            Point::new(-3.0, 2.0),
+        ]);
+        
+        assert_eq!(array.nearest_to(&Point::new(4.0, 3.5)), Some(&Point::new(5.0, 5.0)));
+        assert_eq!(array.nearest_to(&Point::new(-2.0, 1.0)), Some(&Point::new(-3.0, 2.0)));
+        assert_eq!(Array::from_vec(Vec::new()).nearest_to(&Point::ORIGIN), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let array = Array::from_vec(vec![Point::new(1.0, 2.5), Point::new(-3.125, 0.0)]);
+        
+        assert_eq!(array.to_string(), "Array[Point(1.00, 2.50), Point(-3.12, 0.00)]");
+        assert_eq!(format!("{:.1}", array), "Array[Point(1.0, 2.5), Point(-3.1, 0.0)]");
+        assert_eq!(format!("{:.3}", array), "Array[Point(1.000, 2.500), Point(-3.125, 0.000)]");
+        assert_eq!(Array::from_vec(Vec::new()).to_string(), "Array[]");
+    }
+
     #[test]
     fn test_iterators() {
         let mut array = Array::with_size(3);
@@ -327,7 +753,7 @@ mod tests {
         
         // Test mutable iterator
         for point in array.iter_mut() {
-            *point = *point * 2.0;
+            *point *= 2.0;
         }
         
         assert_eq!(array[0], Point::new(2.0, 2.0));
@@ -382,6 +808,25 @@ mod tests {
         assert!(array.is_empty());
     }
 
+    #[test]
+    fn test_insert_remove() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(2.0, 2.0);
+        let c = Point::new(3.0, 3.0);
+        let mut array = Array::from_vec(vec![a, c]);
+        
+        assert_eq!(array.insert(1, b), Ok(()));
+        assert_eq!(array, Array::from_vec(vec![a, b, c]));
+        assert_eq!(array.insert(3, a), Ok(())); // append at the end
+        assert_eq!(array.size(), 4);
+        assert_eq!(array.insert(9, a), Err(GeometryError::IndexOutOfBounds { index: 9, len: 4 }));
+        
+        assert_eq!(array.remove(0), Ok(a));
+        assert_eq!(array, Array::from_vec(vec![b, c, a]));
+        assert_eq!(array.remove(3), Err(GeometryError::IndexOutOfBounds { index: 3, len: 3 }));
+        assert_eq!(array.size(), 3);
+    }
+
     #[test]
     fn test_clone_and_equality() {
         let mut array1 = Array::with_size(2);
@@ -411,4 +856,35 @@ mod tests {
         let back_to_vec: Vec<Point> = array.into();
         assert_eq!(back_to_vec, points);
     }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let array = Array::from_vec(vec![
+            Point::new(1.0, 2.0),
+            Point::new(-3.5, 0.25),
+            Point::new(0.1, 1e6),
+        ]);
+
+        let csv = array.to_csv();
+        assert_eq!(csv, "1,2\n-3.5,0.25\n0.1,1000000\n");
+        assert_eq!(Array::from_csv(&csv), Ok(array.clone()));
+
+        // Header and blank lines are skipped
+        let with_header = format!("x, y\n\n{}", csv);
+        assert_eq!(Array::from_csv(&with_header), Ok(array));
+    }
+
+    #[test]
+    fn test_csv_bad_row_reports_line() {
+        let text = "x,y\n1,2\n3,abc\n";
+        match Array::from_csv(text) {
+            Err(GeometryError::ParseError(message)) => assert!(message.starts_with("line 3:"), "{}", message),
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+
+        // Wrong column count
+        let err = Array::from_csv("1,2,3").unwrap_err();
+        assert!(err.to_string().contains("line 1:"));
+        assert!(Array::from_csv("1,2\nx,y").is_err()); // Header only allowed first
+    }
 }
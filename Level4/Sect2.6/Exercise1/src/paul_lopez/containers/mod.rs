@@ -22,14 +22,14 @@
 mod array;
 
 // Re-export all containers to make them accessible from this module
-pub use array::Array;
+pub use array::{Array, mean_y, max_y, min_y};
 
 // Container-specific utilities and constants
 pub const DEFAULT_CAPACITY: usize = 10;
 
 /// Container module information
 pub fn module_info() -> String {
-    format!("Containers module - Data structure classes for storage and organization")
+    "Containers module - Data structure classes for storage and organization".to_string()
 }
 
 /// Get default capacity for containers
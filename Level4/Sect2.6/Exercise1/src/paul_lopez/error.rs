@@ -0,0 +1,61 @@
+// Shared error type - paul_lopez::error::GeometryError
+// ====================================================
+//
+// One error enum for every fallible operation in the CAD and Containers
+// namespaces, so callers can use `?` across both without converting.
+#![allow(dead_code)]
+
+use std::fmt;
+
+/// Errors reported by the geometry and container types
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryError {
+    /// An index past the end of a container
+    IndexOutOfBounds { index: usize, len: usize },
+    /// Malformed text input (JSON, CSV, ...)
+    ParseError(String),
+    /// A shape without enough extent for the operation (e.g. zero radius)
+    DegenerateShape,
+    /// A division by zero
+    DivisionByZero,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for array of size {}", index, len)
+            }
+            GeometryError::ParseError(message) => write!(f, "parse error: {}", message),
+            GeometryError::DegenerateShape => write!(f, "degenerate shape"),
+            GeometryError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_messages() {
+        assert_eq!(
+            GeometryError::IndexOutOfBounds { index: 10, len: 3 }.to_string(),
+            "index 10 out of bounds for array of size 3"
+        );
+        assert_eq!(
+            GeometryError::ParseError("missing field \"y\"".to_string()).to_string(),
+            "parse error: missing field \"y\""
+        );
+        assert_eq!(GeometryError::DegenerateShape.to_string(), "degenerate shape");
+        assert_eq!(GeometryError::DivisionByZero.to_string(), "division by zero");
+    }
+
+    #[test]
+    fn test_usable_as_boxed_error() {
+        let boxed: Box<dyn std::error::Error> = Box::new(GeometryError::DivisionByZero);
+        assert_eq!(boxed.to_string(), "division by zero");
+    }
+}
@@ -18,6 +18,8 @@
 // Declare sub-modules (sub-namespaces)
 pub mod cad;       // PaulLopez::CAD namespace equivalent
 pub mod containers; // PaulLopez::Containers namespace equivalent
+pub mod error;      // Shared GeometryError type
+pub mod prelude;    // Glob-importable re-exports of the common types
 
 // Re-export commonly used types at the namespace root level
 // This allows access like: paul_lopez::Point instead of paul_lopez::cad::Point
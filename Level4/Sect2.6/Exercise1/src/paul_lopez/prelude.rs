@@ -0,0 +1,54 @@
+// Prelude Module - paul_lopez::prelude
+// ====================================
+//
+// Re-exports the commonly used types from every sub-namespace so that a
+// single glob import is enough:
+//
+// C++:
+//   using namespace PaulLopez::CAD;
+//   using namespace PaulLopez::Containers;
+//
+// Rust (from the crate root; crate::paul_lopez::prelude::* elsewhere):
+//   use paul_lopez::prelude::*;
+//
+// The base Shape class is left out on purpose so that `Shape` here names
+// the polymorphic trait; import the class explicitly from paul_lopez::cad
+// when needed.
+
+pub use super::cad::{Point, Point3D, Vector2D, Line, Circle, CircleRelation, Polygon, Rectangle, Triangle};
+pub use super::cad::{AABB, BoundsBuilder, Matrix2x2};
+pub use super::cad::{Drawable, Measurable, Scalable, Translatable, total_extent};
+pub use super::cad::traits::Shape;
+pub use super::cad::{algorithms, tolerance};
+pub use super::containers::Array;
+pub use super::error::GeometryError;
+
+#[cfg(test)]
+mod tests {
+    use crate::paul_lopez::prelude::*;
+
+    #[test]
+    fn test_prelude_types_usable() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(3.0, 4.0);
+
+        let line = Line::new(a, b);
+        assert_eq!(line.length(), 5.0);
+
+        let c1 = Circle::new(a, 2.0);
+        let c2 = Circle::new(b, 3.0);
+        assert_eq!(c1.classify(&c2), CircleRelation::ExternallyTangent);
+
+        let array = Array::from_vec(vec![a, b]);
+        assert_eq!(array.size(), 2);
+        let points: Vec<Point> = array.into();
+        let bounds = AABB::from_points(&points).unwrap();
+        assert_eq!(*bounds.max(), b);
+
+        let mut builder = BoundsBuilder::new();
+        builder.add(&a);
+        assert!(builder.finish().is_some());
+
+        assert!(tolerance::default_epsilon() > 0.0);
+    }
+}